@@ -0,0 +1,153 @@
+use crate::{errors::MatrixError, Matrix, ToMatrix};
+use std::slice;
+
+impl<T: ToMatrix> Matrix<T> {
+    /// Returns the matrix assembled from a grid of blocks, given as a slice of block rows.
+    /// All blocks in a block row must have the same height, and all blocks in a block column
+    /// must have the same width.
+    /// It'll throw an error if there are no blocks, if the block rows have different lengths,
+    /// or if the dimensions of the blocks don't line up.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let a = Matrix::from(vec![vec![2, 1], vec![1, 3]]).unwrap();
+    /// let b = Matrix::from(vec![vec![1, 1]]).unwrap();
+    /// let zero = Matrix::zero(1, 1);
+    /// let kkt = Matrix::block(&[&[&a, &b.transpose()], &[&b, &zero]]).unwrap();
+    /// let n = Matrix::from(vec![vec![2, 1, 1], vec![1, 3, 1], vec![1, 1, 0]]).unwrap();
+    /// assert_eq!(kkt, n);
+    /// ```
+    pub fn block(blocks: &[&[&Self]]) -> Result<Self, MatrixError> {
+        let Some(first_row) = blocks.first() else {
+            return Err(MatrixError::Empty);
+        };
+        if first_row.is_empty() {
+            return Err(MatrixError::Empty);
+        }
+        if blocks.iter().any(|row| row.len() != first_row.len()) {
+            return Err(MatrixError::UnequalRows);
+        }
+        // The first block row decides the widths and the first block column decides the heights.
+        let widths: Vec<usize> = first_row.iter().map(|block| block.width).collect();
+        let heights: Vec<usize> = blocks.iter().map(|row| row[0].height).collect();
+        for (row, &height) in blocks.iter().zip(&heights) {
+            for (block, &width) in row.iter().zip(&widths) {
+                if (block.height, block.width) != (height, width) {
+                    return Err(MatrixError::DimensionMismatch {
+                        left: (height, width),
+                        right: (block.height, block.width),
+                    });
+                }
+            }
+        }
+
+        let mut out = Self::zero(heights.iter().sum(), widths.iter().sum());
+        let mut i = 0;
+        for (row, &height) in blocks.iter().zip(&heights) {
+            let mut j = 0;
+            for (block, &width) in row.iter().zip(&widths) {
+                out.view_mut(i..i + height, j..j + width)
+                    .copy_from(block.as_view())?;
+                j += width;
+            }
+            i += height;
+        }
+        Ok(out)
+    }
+
+    /// Returns the matrix formed by placing the provided matrices side by side, e.g. the
+    /// augmented matrix `[A | B]`.
+    /// It'll throw an error if there are no matrices or if their heights don't match.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let a = Matrix::from(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// let b = Matrix::from(vec![vec![5], vec![6]]).unwrap();
+    /// let n = Matrix::from(vec![vec![1, 2, 5], vec![3, 4, 6]]).unwrap();
+    /// assert_eq!(Matrix::hstack(&[&a, &b]), Ok(n));
+    /// assert!(Matrix::hstack(&[&a, &b.transpose()]).is_err());
+    /// ```
+    pub fn hstack(matrices: &[&Self]) -> Result<Self, MatrixError> {
+        Self::block(&[matrices])
+    }
+
+    /// Returns the matrix formed by placing the provided matrices on top of each other.
+    /// It'll throw an error if there are no matrices or if their widths don't match.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let a = Matrix::from(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// let b = Matrix::from(vec![vec![5, 6]]).unwrap();
+    /// let n = Matrix::from(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+    /// assert_eq!(Matrix::vstack(&[&a, &b]), Ok(n));
+    /// ```
+    pub fn vstack(matrices: &[&Self]) -> Result<Self, MatrixError> {
+        let blocks: Vec<&[&Self]> = matrices.iter().map(slice::from_ref).collect();
+        Self::block(&blocks)
+    }
+
+    /// Returns the block diagonal matrix with the provided matrices on the diagonal, and
+    /// zeros everywhere else. The blocks can be of any shape.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let a = Matrix::from(vec![vec![1, 2]]).unwrap();
+    /// let b = Matrix::from(vec![vec![3], vec![4]]).unwrap();
+    /// let n = Matrix::from(vec![vec![1, 2, 0], vec![0, 0, 3], vec![0, 0, 4]]).unwrap();
+    /// assert_eq!(Matrix::block_diag(&[&a, &b]), n);
+    /// ```
+    pub fn block_diag(matrices: &[&Self]) -> Self {
+        let height = matrices.iter().map(|m| m.height).sum();
+        let width = matrices.iter().map(|m| m.width).sum();
+        let mut out = Self::zero(height, width);
+        let (mut i, mut j) = (0, 0);
+        for m in matrices {
+            out.view_mut(i..i + m.height, j..j + m.width)
+                .copy_from(m.as_view())
+                .expect("the block has the same dimensions as the view");
+            i += m.height;
+            j += m.width;
+        }
+        out
+    }
+
+    /// Returns the blocks of a matrix split into block rows and columns of the given sizes,
+    /// i.e. the inverse of [`block`](Self::block()).
+    /// It'll throw an error if the sizes don't add up to the dimensions of the matrix.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// let blocks = m.split_blocks(&[1, 1], &[2, 1]).unwrap();
+    /// assert_eq!(blocks[1][0], Matrix::from(vec![vec![4, 5]]).unwrap());
+    /// assert_eq!(blocks[0][1], Matrix::from(vec![vec![3]]).unwrap());
+    /// ```
+    pub fn split_blocks(
+        &self,
+        row_sizes: &[usize],
+        col_sizes: &[usize],
+    ) -> Result<Vec<Vec<Self>>, MatrixError> {
+        let height = row_sizes.iter().sum();
+        let width = col_sizes.iter().sum();
+        if (height, width) != (self.height, self.width) {
+            return Err(MatrixError::DimensionMismatch {
+                left: (self.height, self.width),
+                right: (height, width),
+            });
+        }
+
+        let mut out = Vec::with_capacity(row_sizes.len());
+        let mut i = 0;
+        for &h in row_sizes {
+            let mut row = Vec::with_capacity(col_sizes.len());
+            let mut j = 0;
+            for &w in col_sizes {
+                row.push(self.view(i..i + h, j..j + w).to_matrix());
+                j += w;
+            }
+            out.push(row);
+            i += h;
+        }
+        Ok(out)
+    }
+}
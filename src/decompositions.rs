@@ -0,0 +1,17 @@
+//! Matrix decompositions. Each of these is computed once through a method on
+//! [`Matrix`](crate::Matrix) and can be reused afterwards.
+
+mod cholesky;
+mod echelon;
+mod lu;
+mod qr;
+mod svd;
+mod symmetric_eigen;
+
+pub use cholesky::{Cholesky, Ldlt};
+pub use echelon::Echelon;
+pub use lu::LuDecomposition;
+pub(crate) use qr::householder_reduce;
+pub use qr::QrDecomposition;
+pub use svd::Svd;
+pub use symmetric_eigen::SymmetricEigen;
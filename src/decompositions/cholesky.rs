@@ -0,0 +1,414 @@
+use crate::{errors::MatrixError, Matrix, Pivot, ToMatrix};
+use num::traits::One;
+use std::ops::Div;
+
+/// Cholesky decomposition `A = LLᵀ` of a real symmetric positive definite matrix, where `L`
+/// is lower triangular with a positive diagonal. It's computed by [`Matrix::cholesky()`].
+#[derive(PartialEq, Debug, Clone)]
+pub struct Cholesky {
+    pub(crate) l: Matrix<f64>,
+}
+
+/// `LDLᵀ` decomposition of a symmetric matrix over a field with symmetric pivoting, i.e.
+/// `PAPᵀ = LDLᵀ` where `P` is a permutation matrix, `L` is unit lower triangular and `D` is
+/// block diagonal with blocks of size 1 or 2. It's computed by [`Matrix::ldlt()`].
+#[derive(PartialEq, Debug, Clone)]
+pub struct Ldlt<T: ToMatrix> {
+    pub(crate) l: Matrix<T>,
+    pub(crate) d: Vec<T>,
+    pub(crate) subdiagonal: Vec<T>,
+    pub(crate) permutation: Vec<usize>,
+}
+
+impl Matrix<f64> {
+    /// Returns the Cholesky decomposition of a real symmetric positive definite matrix.
+    /// It's about twice as fast as [`lu`](Self::lu()), and checks for positive definiteness.
+    /// It'll throw an error if the provided matrix isn't square, symmetric (up to a small
+    /// relative tolerance) or positive definite.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![4.0, 2.0], vec![2.0, 5.0]]).unwrap();
+    /// let l = Matrix::from(vec![vec![2.0, 0.0], vec![1.0, 2.0]]).unwrap();
+    /// assert_eq!(m.cholesky().unwrap().l(), &l);
+    /// ```
+    pub fn cholesky(&self) -> Result<Cholesky, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare);
+        }
+        if !self.is_nearly_symmetric() {
+            return Err(MatrixError::NotSymmetric);
+        }
+        let n = self.height();
+        let mut l = Self::zero(n, n);
+        for (i, row) in self.row_slices().enumerate() {
+            for (j, &value) in row.iter().enumerate().take(i + 1) {
+                let dot: f64 = l
+                    .row_slice(i)
+                    .iter()
+                    .zip(l.row_slice(j))
+                    .take(j)
+                    .map(|(x, y)| x * y)
+                    .sum();
+                let entry = value - dot;
+                if i == j {
+                    // This fails exactly when the matrix isn't positive definite.
+                    if entry <= 0.0 || entry.is_nan() {
+                        return Err(MatrixError::NotPositiveDefinite);
+                    }
+                    l.entries[i * n + i] = entry.sqrt();
+                } else {
+                    l.entries[i * n + j] = entry / l.entries[j * n + j];
+                }
+            }
+        }
+        Ok(Cholesky { l })
+    }
+}
+
+impl Cholesky {
+    /// Returns the lower triangular factor `L`.
+    pub fn l(&self) -> &Matrix<f64> {
+        &self.l
+    }
+
+    /// Solves `AX = B` for `X`, where `A` is the decomposed matrix and `B` is given.
+    /// All columns of `B` are solved for at once.
+    /// It'll throw an error if the height of `B` doesn't match.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let a = Matrix::from(vec![vec![4.0, 2.0], vec![2.0, 5.0]]).unwrap();
+    /// let b = Matrix::from(vec![vec![6.0], vec![7.0]]).unwrap();
+    /// let x = Matrix::from(vec![vec![1.0], vec![1.0]]).unwrap();
+    /// assert_eq!(a.cholesky().unwrap().solve(&b), Ok(x));
+    /// ```
+    pub fn solve(&self, rhs: &Matrix<f64>) -> Result<Matrix<f64>, MatrixError> {
+        let n = self.l.height();
+        if rhs.height() != n {
+            return Err(MatrixError::DimensionMismatch {
+                left: (n, n),
+                right: (rhs.height(), rhs.width()),
+            });
+        }
+        let mut out = rhs.clone();
+        let k = out.width;
+        // First we solve LY = B by forward substitution
+        for (i, l_row) in self.l.row_slices().enumerate() {
+            let (above, below) = out.entries.split_at_mut(i * k);
+            let row = &mut below[..k];
+            for (other, ratio) in above.chunks_exact(k.max(1)).zip(l_row) {
+                for (entry, other_entry) in row.iter_mut().zip(other) {
+                    *entry -= other_entry * ratio;
+                }
+            }
+            for entry in row.iter_mut() {
+                *entry /= l_row[i];
+            }
+        }
+        // Then LᵀX = Y by backward substitution
+        for i in (0..n).rev() {
+            let (above, below) = out.entries.split_at_mut((i + 1) * k);
+            let row = &mut above[i * k..];
+            for (other, l_row) in below
+                .chunks_exact(k.max(1))
+                .zip(self.l.row_slices().skip(i + 1))
+            {
+                for (entry, other_entry) in row.iter_mut().zip(other) {
+                    *entry -= other_entry * l_row[i];
+                }
+            }
+            let divisor = self.l.entries[i * n + i];
+            for entry in row.iter_mut() {
+                *entry /= divisor;
+            }
+        }
+        Ok(out)
+    }
+}
+
+impl<T: ToMatrix> Matrix<T> {
+    /// Returns the `LDLᵀ` decomposition of a symmetric matrix over a field i.e. needs [`One`]
+    /// and [`Div`] traits. Unlike [`cholesky`](Matrix::cholesky()), it works for indefinite
+    /// matrices too, and doesn't need square roots. Symmetric pivoting is done using the
+    /// Bunch–Kaufman strategy (with `α = 2/3`), where the magnitudes are compared according to
+    /// [`Pivot`]. When no diagonal entry is a suitable pivot, a `2×2` block is used instead,
+    /// so any invertible symmetric matrix can be decomposed, e.g. ones with zero diagonals.
+    /// It'll throw an error if the provided matrix isn't square, symmetric or invertible.
+    /// Symmetry is checked up to the [tolerance](Pivot::tolerance()) of [`Pivot`], so that
+    /// floating point matrices with rounding errors are accepted (using their lower triangle),
+    /// while it's checked exactly for exact types like integers or rationals.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![4.0, 2.0], vec![2.0, -1.0]]).unwrap();
+    /// let ldlt = m.ldlt().unwrap();
+    /// let l = Matrix::from(vec![vec![1.0, 0.0], vec![0.5, 1.0]]).unwrap();
+    /// assert_eq!(ldlt.l(), &l);
+    /// assert_eq!(ldlt.d(), &vec![4.0, -2.0]);
+    ///
+    /// let m = Matrix::from(vec![vec![0.0, 1.0], vec![1.0, 0.0]]).unwrap();
+    /// let ldlt = m.ldlt().unwrap();
+    /// assert_eq!(ldlt.d_matrix(), m);
+    /// ```
+    pub fn ldlt(&self) -> Result<Ldlt<T>, MatrixError>
+    where
+        T: One,
+        T: Pivot,
+        T: Div<Output = T>,
+    {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare);
+        }
+        let n = self.height();
+        let tolerance = T::tolerance(&self.entries, n);
+        let mut a = self.clone();
+        // The matrix only has to be symmetric up to the tolerance, and then its lower
+        // triangle is used.
+        for i in 0..n {
+            for j in 0..i {
+                let lower = a.entries[i * n + j];
+                if !(lower - a.entries[j * n + i]).is_negligible(&tolerance) {
+                    return Err(MatrixError::NotSymmetric);
+                }
+                a.entries[j * n + i] = lower;
+            }
+        }
+        let mut l = Self::identity(n);
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut d = vec![T::zero(); n];
+        let mut subdiagonal = vec![T::zero(); n.saturating_sub(1)];
+        let two = T::one() + T::one();
+        let alpha = two / (two + T::one());
+        // Returns true if the magnitude of x is at least that of y. For exact types,
+        // this is true exactly when x is non-zero.
+        let at_least = |x: T, y: T| T::pivot(&[x, y]) == Some(0);
+
+        let mut k = 0;
+        while k < n {
+            // Choose the pivot r and the size of the block.
+            let column: Vec<T> = (k + 1..n).map(|i| a.entries[i * n + k]).collect();
+            let diagonal = a.entries[k * n + k];
+            let (r, size) = match T::pivot(&column) {
+                None if diagonal == T::zero() => return Err(MatrixError::Singular),
+                // There's nothing to eliminate.
+                None => (k, 1),
+                Some(i) => {
+                    let r = k + 1 + i;
+                    let lambda = column[i];
+                    if at_least(diagonal, alpha * lambda) {
+                        (k, 1)
+                    } else {
+                        // The largest off-diagonal entry in column r.
+                        let column_r: Vec<T> = (k..n)
+                            .filter(|&j| j != r)
+                            .map(|j| a.entries[j * n + r])
+                            .collect();
+                        let sigma = T::pivot(&column_r).map_or(lambda, |j| column_r[j]);
+                        if at_least(diagonal * sigma, alpha * lambda * lambda) {
+                            (k, 1)
+                        } else if at_least(a.entries[r * n + r], alpha * sigma) {
+                            (r, 1)
+                        } else {
+                            (r, 2)
+                        }
+                    }
+                }
+            };
+            // Move the pivot to position k, or k + 1 for 2×2 blocks.
+            let target = k + size - 1;
+            if r != target {
+                a.swap_rows(target, r);
+                for row in a.row_slices_mut() {
+                    row.swap(target, r);
+                }
+                for c in 0..k {
+                    l.entries.swap(target * n + c, r * n + c);
+                }
+                permutation.swap(target, r);
+            }
+
+            if size == 1 {
+                let pivot = a.entries[k * n + k];
+                d[k] = pivot;
+                for i in k + 1..n {
+                    l.entries[i * n + k] = a.entries[i * n + k] / pivot;
+                }
+                // Update the Schur complement, keeping it exactly symmetric.
+                for i in k + 1..n {
+                    let ratio = l.entries[i * n + k];
+                    for j in k + 1..=i {
+                        let entry = a.entries[i * n + j] - ratio * a.entries[j * n + k];
+                        a.entries[i * n + j] = entry;
+                        a.entries[j * n + i] = entry;
+                    }
+                }
+            } else {
+                let (p, q, s) = (
+                    a.entries[k * n + k],
+                    a.entries[(k + 1) * n + k],
+                    a.entries[(k + 1) * n + k + 1],
+                );
+                d[k] = p;
+                d[k + 1] = s;
+                subdiagonal[k] = q;
+                // Multiply the two columns by the inverse of the block.
+                let det = p * s - q * q;
+                for i in k + 2..n {
+                    let (x, y) = (a.entries[i * n + k], a.entries[i * n + k + 1]);
+                    l.entries[i * n + k] = (x * s - y * q) / det;
+                    l.entries[i * n + k + 1] = (y * p - x * q) / det;
+                }
+                for i in k + 2..n {
+                    let (first, second) = (l.entries[i * n + k], l.entries[i * n + k + 1]);
+                    for j in k + 2..=i {
+                        let entry = a.entries[i * n + j]
+                            - first * a.entries[j * n + k]
+                            - second * a.entries[j * n + k + 1];
+                        a.entries[i * n + j] = entry;
+                        a.entries[j * n + i] = entry;
+                    }
+                }
+            }
+            k += size;
+        }
+        Ok(Ldlt {
+            l,
+            d,
+            subdiagonal,
+            permutation,
+        })
+    }
+}
+
+impl<T: ToMatrix> Ldlt<T>
+where
+    T: One,
+    T: PartialEq,
+    T: Div<Output = T>,
+{
+    /// Returns the unit lower triangular factor `L`.
+    pub fn l(&self) -> &Matrix<T> {
+        &self.l
+    }
+
+    /// Returns the diagonal of `D`.
+    pub fn d(&self) -> &Vec<T> {
+        &self.d
+    }
+
+    /// Returns the subdiagonal of `D`, whose non-zero entries mark the `2×2` blocks.
+    pub fn subdiagonal(&self) -> &Vec<T> {
+        &self.subdiagonal
+    }
+
+    /// Returns the block diagonal matrix `D`.
+    pub fn d_matrix(&self) -> Matrix<T> {
+        let n = self.d.len();
+        let mut out = Matrix::diagonal_matrix(self.d.clone());
+        for (k, &entry) in self.subdiagonal.iter().enumerate() {
+            out.entries[(k + 1) * n + k] = entry;
+            out.entries[k * n + k + 1] = entry;
+        }
+        out
+    }
+
+    /// Returns the symmetric permutation. The `i`-th row of `PAPᵀ` is the
+    /// `permutation()[i]`-th row of the original matrix, with its entries permuted in the
+    /// same way.
+    pub fn permutation(&self) -> &Vec<usize> {
+        &self.permutation
+    }
+
+    /// Returns the permutation matrix `P`.
+    pub fn p(&self) -> Matrix<T> {
+        let size = self.permutation.len();
+        let mut out = Matrix::zero(size, size);
+        for (row, &j) in out.row_slices_mut().zip(&self.permutation) {
+            row[j] = T::one();
+        }
+        out
+    }
+
+    /// Solves `AX = B` for `X`, where `A` is the decomposed matrix and `B` is given.
+    /// All columns of `B` are solved for at once.
+    /// It'll throw an error if the height of `B` doesn't match.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let a = Matrix::from(vec![vec![1.0, 2.0], vec![2.0, 1.0]]).unwrap();
+    /// let b = Matrix::from(vec![vec![3.0], vec![3.0]]).unwrap();
+    /// let x = Matrix::from(vec![vec![1.0], vec![1.0]]).unwrap();
+    /// assert_eq!(a.ldlt().unwrap().solve(&b), Ok(x));
+    /// ```
+    pub fn solve(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        let n = self.l.height();
+        if rhs.height() != n {
+            return Err(MatrixError::DimensionMismatch {
+                left: (n, n),
+                right: (rhs.height(), rhs.width()),
+            });
+        }
+        let k = rhs.width;
+        let mut entries = Vec::with_capacity(rhs.entries.len());
+        for &i in &self.permutation {
+            entries.extend_from_slice(rhs.row_slice(i));
+        }
+        let mut out = Matrix {
+            entries,
+            height: n,
+            width: k,
+        };
+        // First we solve LY = PB by forward substitution
+        for (i, l_row) in self.l.row_slices().enumerate() {
+            let (above, below) = out.entries.split_at_mut(i * k);
+            let row = &mut below[..k];
+            for (other, ratio) in above.chunks_exact(k.max(1)).zip(l_row) {
+                for (entry, other_entry) in row.iter_mut().zip(other) {
+                    *entry = *entry - *other_entry * *ratio;
+                }
+            }
+        }
+        // Then DZ = Y, block by block
+        let mut i = 0;
+        while i < n {
+            if i + 1 < n && self.subdiagonal[i] != T::zero() {
+                let (p, q, s) = (self.d[i], self.subdiagonal[i], self.d[i + 1]);
+                let det = p * s - q * q;
+                let (first, second) = out.row_pair_mut(i, i + 1);
+                for (x, y) in first.iter_mut().zip(second.iter_mut()) {
+                    let (old_x, old_y) = (*x, *y);
+                    *x = (s * old_x - q * old_y) / det;
+                    *y = (p * old_y - q * old_x) / det;
+                }
+                i += 2;
+            } else {
+                let divisor = self.d[i];
+                for entry in out.row_slice_mut(i) {
+                    *entry = *entry / divisor;
+                }
+                i += 1;
+            }
+        }
+        // Then LᵀW = Z by backward substitution
+        for i in (0..n).rev() {
+            let (above, below) = out.entries.split_at_mut((i + 1) * k);
+            let row = &mut above[i * k..];
+            for (other, l_row) in below
+                .chunks_exact(k.max(1))
+                .zip(self.l.row_slices().skip(i + 1))
+            {
+                for (entry, other_entry) in row.iter_mut().zip(other) {
+                    *entry = *entry - *other_entry * l_row[i];
+                }
+            }
+        }
+        // And finally X = PᵀW
+        let mut entries = vec![T::zero(); out.entries.len()];
+        for (row, &i) in out.row_slices().zip(&self.permutation) {
+            entries[i * k..(i + 1) * k].copy_from_slice(row);
+        }
+        out.entries = entries;
+        Ok(out)
+    }
+}
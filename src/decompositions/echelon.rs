@@ -0,0 +1,87 @@
+use crate::{Matrix, Pivot, ToMatrix};
+use num::traits::One;
+use std::ops::Div;
+
+/// Reduced row echelon form of a matrix together with the data found while computing it.
+/// It's computed by [`Matrix::reduced_echelon()`].
+#[derive(PartialEq, Debug, Clone)]
+pub struct Echelon<T: ToMatrix> {
+    pub(crate) matrix: Matrix<T>,
+    pub(crate) pivots: Vec<usize>,
+    pub(crate) permutation: Vec<usize>,
+}
+
+impl<T: ToMatrix> Matrix<T> {
+    /// Returns the reduced row echelon form of a matrix over a field i.e. needs [`One`] and
+    /// [`Div`] traits, along with the pivot columns, the row permutation and the rank.
+    /// Zero rows are moved to the bottom, so it works for rank deficient matrices of any shape.
+    /// Like in [`rank`](Self::rank()), [negligible](Pivot::is_negligible()) pivots are treated
+    /// as zero.
+    /// See [`reduced_row_echelon`](Self::reduced_row_echelon()) if only the matrix is needed.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![1.0, 2.0, 1.0], vec![2.0, 4.0, 4.0], vec![0.0, 0.0, 1.0]]).unwrap();
+    /// let echelon = m.reduced_echelon();
+    /// let n = Matrix::from(vec![vec![1.0, 2.0, 0.0], vec![0.0, 0.0, 1.0], vec![0.0, 0.0, 0.0]]).unwrap();
+    /// assert_eq!(echelon.matrix(), &n);
+    /// assert_eq!(echelon.pivots(), &vec![0, 2]);
+    /// assert_eq!(echelon.rank(), 2);
+    /// ```
+    pub fn reduced_echelon(&self) -> Echelon<T>
+    where
+        T: One,
+        T: Pivot,
+        T: Div<Output = T>,
+    {
+        let lu = self.clone().into_rank_revealing_lu();
+        let mut matrix = lu.u;
+        let w = matrix.width;
+        for (i, &p) in lu.pivots.iter().enumerate().rev() {
+            let (above, below) = matrix.entries.split_at_mut(i * w);
+            let pivot_row = &mut below[..w];
+            let divisor = pivot_row[p];
+            pivot_row[p] = T::one();
+            for entry in pivot_row.iter_mut().skip(p + 1) {
+                *entry = *entry / divisor;
+            }
+            // Clear the pivot column above the pivot, setting it exactly to avoid rounding errors.
+            for row in above.chunks_exact_mut(w) {
+                let ratio = row[p];
+                row[p] = T::zero();
+                for (entry, pivot_entry) in row.iter_mut().zip(&*pivot_row).skip(p + 1) {
+                    *entry = *entry - *pivot_entry * ratio;
+                }
+            }
+        }
+        Echelon {
+            matrix,
+            pivots: lu.pivots,
+            permutation: lu.permutation,
+        }
+    }
+}
+
+impl<T: ToMatrix> Echelon<T> {
+    /// Returns the reduced row echelon form.
+    pub fn matrix(&self) -> &Matrix<T> {
+        &self.matrix
+    }
+
+    /// Returns the (0-indexed) columns containing the pivots, one for each non-zero row.
+    pub fn pivots(&self) -> &Vec<usize> {
+        &self.pivots
+    }
+
+    /// Returns the row permutation used during elimination. The `i`-th row of the
+    /// reduced form is a combination of the `permutation()[i]`-th row of the original
+    /// matrix and the rows before it in this order.
+    pub fn permutation(&self) -> &Vec<usize> {
+        &self.permutation
+    }
+
+    /// Returns the rank, i.e. the number of pivots.
+    pub fn rank(&self) -> usize {
+        self.pivots.len()
+    }
+}
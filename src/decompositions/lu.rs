@@ -0,0 +1,310 @@
+use crate::{errors::MatrixError, Matrix, Pivot, ToMatrix};
+use num::traits::One;
+use std::ops::Div;
+
+/// LU decomposition of a matrix with row pivoting, i.e. `PA = LU` where `P` is a
+/// permutation matrix, `L` is unit lower triangular and `U` is in row echelon form.
+/// It's computed once by [`Matrix::lu()`] and can then be reused for determinants,
+/// solving linear systems, inverses and ranks.
+#[derive(PartialEq, Debug, Clone)]
+pub struct LuDecomposition<T: ToMatrix> {
+    pub(crate) l: Matrix<T>,
+    pub(crate) u: Matrix<T>,
+    pub(crate) permutation: Vec<usize>,
+    pub(crate) pivots: Vec<usize>,
+    pub(crate) odd_permutation: bool,
+}
+
+impl<T: ToMatrix> Matrix<T> {
+    /// Returns the LU decomposition of a matrix over a field i.e. needs [`One`] and [`Div`] traits.
+    /// Works for matrices of any shape, and the pivots are chosen according to [`Pivot`].
+    /// Only columns whose candidates are all exactly zero get no pivot, so small but non-zero
+    /// pivots are kept. See [`rank`](Self::rank()) for the numerical rank of floating point
+    /// matrices, and [`LuDecomposition`] for what can be done with the decomposition.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+    /// let lu = m.lu();
+    /// assert_eq!(lu.det(), Ok(-2.0));
+    /// assert_eq!(lu.rank(), 2);
+    /// ```
+    pub fn lu(&self) -> LuDecomposition<T>
+    where
+        T: One,
+        T: Pivot,
+        T: Div<Output = T>,
+    {
+        self.clone().into_lu()
+    }
+
+    /// Returns the LU decomposition of a matrix using the provided pivoting strategy,
+    /// which gets the candidate entries of a column and returns the index of the chosen one.
+    /// This can be used to keep the exact-zero strategy even for floating point types.
+    /// # Example
+    /// ```
+    /// use matrix_basic::{Matrix, Pivot};
+    /// let m = Matrix::from(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+    /// let u = Matrix::from(vec![vec![1.0, 2.0], vec![0.0, -2.0]]).unwrap();
+    /// assert_eq!(m.lu_with(f64::first_non_zero).u(), &u);
+    /// ```
+    pub fn lu_with<F>(&self, pivot: F) -> LuDecomposition<T>
+    where
+        T: One,
+        T: PartialEq,
+        T: Div<Output = T>,
+        F: Fn(&[T]) -> Option<usize>,
+    {
+        // Cloning is necessary as we'll be doing row operations on it.
+        self.clone().into_lu_with(pivot)
+    }
+
+    /// Returns the LU decomposition like [`lu`](Self::lu()), doing the row operations in place.
+    pub(crate) fn into_lu(self) -> LuDecomposition<T>
+    where
+        T: One,
+        T: Pivot,
+        T: Div<Output = T>,
+    {
+        self.into_lu_with(T::pivot)
+    }
+
+    /// Returns the LU decomposition like [`into_lu`](Self::into_lu()), except that columns
+    /// whose candidates are all [negligible](Pivot::is_negligible()) get no pivot. It's used
+    /// where the rank matters, so that rounding errors don't make the matrix look like it has
+    /// full rank.
+    pub(crate) fn into_rank_revealing_lu(self) -> LuDecomposition<T>
+    where
+        T: One,
+        T: Pivot,
+        T: Div<Output = T>,
+    {
+        let tolerance = T::tolerance(&self.entries, self.height.max(self.width));
+        self.into_lu_with_tolerance(tolerance)
+    }
+
+    /// Returns the LU decomposition like [`into_rank_revealing_lu`](Self::into_rank_revealing_lu()),
+    /// using the provided tolerance.
+    pub(crate) fn into_lu_with_tolerance(self, tolerance: T) -> LuDecomposition<T>
+    where
+        T: One,
+        T: Pivot,
+        T: Div<Output = T>,
+    {
+        self.into_lu_with(|candidates| {
+            T::pivot(candidates).filter(|&p| !candidates[p].is_negligible(&tolerance))
+        })
+    }
+
+    /// Returns the LU decomposition like [`lu_with`](Self::lu_with()), doing the row
+    /// operations in place.
+    pub(crate) fn into_lu_with<F>(self, pivot: F) -> LuDecomposition<T>
+    where
+        T: One,
+        T: PartialEq,
+        T: Div<Output = T>,
+        F: Fn(&[T]) -> Option<usize>,
+    {
+        let h = self.height();
+        let w = self.width();
+        let mut u = self;
+        let mut l = Self::zero(h, h);
+        let mut permutation: Vec<usize> = (0..h).collect();
+        let mut pivots = Vec::new();
+        let mut odd_permutation = false;
+
+        let mut i = 0;
+        for col in 0..w {
+            if i == h {
+                break;
+            }
+            // Look for a pivot in this column, if there's none, we move to the next one.
+            let candidates: Vec<T> = u
+                .entries
+                .iter()
+                .skip(i * w + col)
+                .step_by(w)
+                .copied()
+                .collect();
+            let Some(p) = pivot(&candidates) else {
+                // The entries are zero, or negligible, so they're cleared.
+                for entry in u.entries.iter_mut().skip(i * w + col).step_by(w) {
+                    *entry = T::zero();
+                }
+                continue;
+            };
+            if p != 0 {
+                // The multipliers stored in l have to move along with the rows.
+                u.swap_rows(i, i + p);
+                l.swap_rows(i, i + p);
+                permutation.swap(i, i + p);
+                odd_permutation = !odd_permutation;
+            }
+            let (above, below) = u.entries.split_at_mut((i + 1) * w);
+            let pivot_row = &above[i * w..];
+            for (j, row) in below.chunks_exact_mut(w).enumerate() {
+                let ratio = row[col] / pivot_row[col];
+                l.entries[(i + 1 + j) * h + i] = ratio;
+                // The eliminated entry is set exactly, so that no rounding errors are left.
+                row[col] = T::zero();
+                for (entry, pivot_entry) in row.iter_mut().zip(pivot_row).skip(col + 1) {
+                    *entry = *entry - *pivot_entry * ratio;
+                }
+            }
+            pivots.push(col);
+            i += 1;
+        }
+        for entry in l.entries.iter_mut().step_by(h + 1) {
+            *entry = T::one();
+        }
+
+        LuDecomposition {
+            l,
+            u,
+            permutation,
+            pivots,
+            odd_permutation,
+        }
+    }
+}
+
+impl<T: ToMatrix> LuDecomposition<T>
+where
+    T: One,
+    T: PartialEq,
+    T: Div<Output = T>,
+{
+    /// Returns the unit lower triangular factor `L`.
+    pub fn l(&self) -> &Matrix<T> {
+        &self.l
+    }
+
+    /// Returns the upper triangular (row echelon) factor `U`.
+    pub fn u(&self) -> &Matrix<T> {
+        &self.u
+    }
+
+    /// Returns the row permutation. The `i`-th row of `PA` is the
+    /// `permutation()[i]`-th row of the original matrix.
+    pub fn permutation(&self) -> &Vec<usize> {
+        &self.permutation
+    }
+
+    /// Returns the permutation matrix `P`.
+    pub fn p(&self) -> Matrix<T> {
+        let size = self.permutation.len();
+        let mut out = Matrix::zero(size, size);
+        for (row, &j) in out.row_slices_mut().zip(&self.permutation) {
+            row[j] = T::one();
+        }
+        out
+    }
+
+    /// Returns the (0-indexed) columns of `U` containing the pivots.
+    pub fn pivots(&self) -> &Vec<usize> {
+        &self.pivots
+    }
+
+    /// Returns the rank of the decomposed matrix, i.e. the number of non-zero pivots.
+    /// Small pivots coming from rounding errors are counted, see [`Matrix::rank()`] for
+    /// the numerical rank.
+    pub fn rank(&self) -> usize {
+        self.pivots.len()
+    }
+
+    /// Returns the determinant of the decomposed matrix.
+    /// It'll throw an error if the decomposed matrix isn't square.
+    pub fn det(&self) -> Result<T, MatrixError> {
+        if !self.u.is_square() {
+            return Err(MatrixError::NotSquare);
+        }
+        if self.rank() < self.u.height() {
+            return Ok(T::zero());
+        }
+        let mut out = T::one();
+        for entry in self.u.entries.iter().step_by(self.u.width + 1) {
+            out = out * *entry;
+        }
+        if self.odd_permutation {
+            out = -out;
+        }
+        Ok(out)
+    }
+
+    /// Solves `AX = B` for `X`, where `A` is the decomposed matrix and `B` is given.
+    /// All columns of `B` are solved for at once.
+    /// It'll throw an error if `A` isn't square, is singular, or the height of `B` doesn't match.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let a = Matrix::from(vec![vec![2.0, 1.0], vec![1.0, 3.0]]).unwrap();
+    /// let b = Matrix::from(vec![vec![3.0], vec![4.0]]).unwrap();
+    /// let x = Matrix::from(vec![vec![1.0], vec![1.0]]).unwrap();
+    /// assert_eq!(a.lu().solve(&b), Ok(x));
+    /// ```
+    pub fn solve(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if !self.u.is_square() {
+            return Err(MatrixError::NotSquare);
+        }
+        let n = self.u.height();
+        if rhs.height() != n {
+            return Err(MatrixError::DimensionMismatch {
+                left: (n, n),
+                right: (rhs.height(), rhs.width()),
+            });
+        }
+        if self.rank() < n {
+            return Err(MatrixError::Singular);
+        }
+        // First we solve LY = PB by forward substitution
+        let mut out = self.forward_substitute(rhs);
+        let k = out.width;
+        // Then UX = Y by backward substitution
+        for (i, u_row) in self.u.row_slices().enumerate().rev() {
+            let (above, below) = out.entries.split_at_mut((i + 1) * k);
+            let row = &mut above[i * k..];
+            for (other, &ratio) in below.chunks_exact(k.max(1)).zip(&u_row[i + 1..]) {
+                for (entry, other_entry) in row.iter_mut().zip(other) {
+                    *entry = *entry - *other_entry * ratio;
+                }
+            }
+            let divisor = u_row[i];
+            for entry in row.iter_mut() {
+                *entry = *entry / divisor;
+            }
+        }
+
+        Ok(out)
+    }
+
+    /// Returns `Y` such that `LY = PB`, using forward substitution.
+    /// The height of `B` must match that of the decomposed matrix.
+    pub(crate) fn forward_substitute(&self, rhs: &Matrix<T>) -> Matrix<T> {
+        let k = rhs.width;
+        let mut entries = Vec::with_capacity(rhs.entries.len());
+        for &i in &self.permutation {
+            entries.extend_from_slice(rhs.row_slice(i));
+        }
+        let mut out = Matrix {
+            entries,
+            height: rhs.height,
+            width: k,
+        };
+        for (i, l_row) in self.l.row_slices().enumerate() {
+            let (above, below) = out.entries.split_at_mut(i * k);
+            let row = &mut below[..k];
+            for (other, &ratio) in above.chunks_exact(k.max(1)).zip(l_row) {
+                for (entry, other_entry) in row.iter_mut().zip(other) {
+                    *entry = *entry - *other_entry * ratio;
+                }
+            }
+        }
+        out
+    }
+
+    /// Returns the inverse of the decomposed matrix.
+    /// It'll throw an error if the decomposed matrix isn't square or is singular.
+    pub fn inverse(&self) -> Result<Matrix<T>, MatrixError> {
+        self.solve(&Matrix::identity(self.u.height()))
+    }
+}
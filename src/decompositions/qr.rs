@@ -0,0 +1,218 @@
+use crate::Matrix;
+
+/// QR decomposition of a real matrix, i.e. `AP = QR` where `Q` is orthogonal, `R` is upper
+/// triangular and `P` is a permutation matrix (which is the identity unless column pivoting
+/// is used). It's computed by [`Matrix::qr()`], [`Matrix::qr_givens()`] or
+/// [`Matrix::qr_with_column_pivoting()`].
+#[derive(PartialEq, Debug, Clone)]
+pub struct QrDecomposition {
+    pub(crate) q: Matrix<f64>,
+    pub(crate) r: Matrix<f64>,
+    pub(crate) permutation: Vec<usize>,
+}
+
+impl Matrix<f64> {
+    /// Returns the QR decomposition of a real matrix of any shape using Householder reflections.
+    /// `Q` is a square matrix of the same height, and `R` has the same shape as the provided matrix.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![3.0, 1.0], vec![4.0, 2.0]]).unwrap();
+    /// let qr = m.qr();
+    /// let r = Matrix::from(vec![vec![-5.0, -2.2], vec![0.0, -0.4]]).unwrap();
+    /// assert!(qr.r().rows().iter().flatten().zip(r.rows().iter().flatten()).all(|(x, y)| (x - y).abs() < 1e-12));
+    /// ```
+    pub fn qr(&self) -> QrDecomposition {
+        self.householder_qr(false)
+    }
+
+    /// Returns the QR decomposition of a real matrix using Householder reflections with column
+    /// pivoting, i.e. at each step the remaining column with the largest norm is used. This makes
+    /// the diagonal of `R` decreasing in absolute value, which reveals the (numerical) rank,
+    /// see [`QrDecomposition::rank()`].
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![1.0, 2.0, 3.0], vec![2.0, 4.0, 6.0], vec![1.0, 0.0, 1.0]]).unwrap();
+    /// let qr = m.qr_with_column_pivoting();
+    /// assert_eq!(qr.permutation()[0], 2);
+    /// assert_eq!(qr.rank(), 2);
+    /// ```
+    pub fn qr_with_column_pivoting(&self) -> QrDecomposition {
+        self.householder_qr(true)
+    }
+
+    /// Returns the QR decomposition of a real matrix of any shape using Givens rotations.
+    /// It does more work than [`qr`](Self::qr()) for dense matrices, but it only touches two rows
+    /// at a time and skips entries that are already zero, which makes it suitable for sparse
+    /// matrices.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![3.0, 1.0], vec![4.0, 2.0]]).unwrap();
+    /// let qr = m.qr_givens();
+    /// let r = Matrix::from(vec![vec![5.0, 2.2], vec![0.0, 0.4]]).unwrap();
+    /// assert!(qr.r().rows().iter().flatten().zip(r.rows().iter().flatten()).all(|(x, y)| (x - y).abs() < 1e-12));
+    /// ```
+    pub fn qr_givens(&self) -> QrDecomposition {
+        let h = self.height();
+        let w = self.width();
+        let mut r = self.clone();
+        let mut q = Self::identity(h);
+        for j in 0..w.min(h) {
+            for i in ((j + 1)..h).rev() {
+                let (pivot, entry) = (r.entries[j * w + j], r.entries[i * w + j]);
+                if entry == 0.0 {
+                    continue;
+                }
+                // Rotate rows j and i so that the (i, j) entry vanishes.
+                let norm = pivot.hypot(entry);
+                let c = pivot / norm;
+                let s = entry / norm;
+                let (upper, lower) = r.row_pair_mut(j, i);
+                for (x, y) in upper.iter_mut().zip(lower.iter_mut()) {
+                    let (old_x, old_y) = (*x, *y);
+                    *x = c * old_x + s * old_y;
+                    *y = c * old_y - s * old_x;
+                }
+                r.entries[i * w + j] = 0.0;
+                // Q gets multiplied by the transpose of the rotation.
+                for row in q.row_slices_mut() {
+                    let (x, y) = (row[j], row[i]);
+                    row[j] = c * x + s * y;
+                    row[i] = c * y - s * x;
+                }
+            }
+        }
+
+        QrDecomposition {
+            q,
+            r,
+            permutation: (0..w).collect(),
+        }
+    }
+
+    /// Computes the QR decomposition using Householder reflections, optionally with column pivoting.
+    fn householder_qr(&self, pivoting: bool) -> QrDecomposition {
+        let mut r = self.clone();
+        let mut q = Self::identity(self.height());
+        let permutation = householder_reduce(&mut r, self.width(), pivoting, Some(&mut q));
+        QrDecomposition { q, r, permutation }
+    }
+}
+
+/// Reduces the first `columns` columns of `r` to upper triangular form using Householder
+/// reflections, optionally with column pivoting among them, and returns the column permutation.
+/// The reflections are applied to all the columns of `r`, so the remaining ones get multiplied
+/// by `Qᵀ`, and to `q` from the right if it's provided.
+pub(crate) fn householder_reduce(
+    r: &mut Matrix<f64>,
+    columns: usize,
+    pivoting: bool,
+    mut q: Option<&mut Matrix<f64>>,
+) -> Vec<usize> {
+    let h = r.height();
+    let w = r.width();
+    let mut permutation: Vec<usize> = (0..columns).collect();
+    for k in 0..columns.min(h) {
+        if pivoting {
+            // Move the remaining column with the largest norm to the front.
+            let norms: Vec<f64> = (k..columns)
+                .map(|j| r.row_slices().skip(k).map(|row| row[j] * row[j]).sum())
+                .collect();
+            let mut p = 0;
+            for (j, norm) in norms.iter().enumerate() {
+                if *norm > norms[p] {
+                    p = j;
+                }
+            }
+            if p != 0 {
+                for row in r.row_slices_mut() {
+                    row.swap(k, k + p);
+                }
+                permutation.swap(k, k + p);
+            }
+        }
+
+        // Find the Householder vector v for the part of column k below the diagonal,
+        // so that (I - 2vvᵀ) maps it to a multiple of the first unit vector.
+        let mut v: Vec<f64> = r.row_slices().skip(k).map(|row| row[k]).collect();
+        let norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            continue;
+        }
+        let alpha = if v[0] > 0.0 { -norm } else { norm };
+        v[0] -= alpha;
+        let v_norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+        for x in v.iter_mut() {
+            *x /= v_norm;
+        }
+
+        // Apply the reflection to R from the left, and to Q from the right.
+        for j in k..w {
+            let dot: f64 = r
+                .row_slices()
+                .skip(k)
+                .zip(&v)
+                .map(|(row, x)| row[j] * x)
+                .sum();
+            for (row, x) in r.row_slices_mut().skip(k).zip(&v) {
+                row[j] -= 2.0 * dot * x;
+            }
+        }
+        if let Some(q) = q.as_deref_mut() {
+            for row in q.row_slices_mut() {
+                let dot: f64 = row.iter().skip(k).zip(&v).map(|(y, x)| y * x).sum();
+                for (y, x) in row.iter_mut().skip(k).zip(&v) {
+                    *y -= 2.0 * dot * x;
+                }
+            }
+        }
+        r.entries[k * w + k] = alpha;
+        for row in r.row_slices_mut().skip(k + 1) {
+            row[k] = 0.0;
+        }
+    }
+    permutation
+}
+
+impl QrDecomposition {
+    /// Returns the orthogonal factor `Q`.
+    pub fn q(&self) -> &Matrix<f64> {
+        &self.q
+    }
+
+    /// Returns the upper triangular factor `R`.
+    pub fn r(&self) -> &Matrix<f64> {
+        &self.r
+    }
+
+    /// Returns the column permutation. The `j`-th column of `AP` is the
+    /// `permutation()[j]`-th column of the original matrix.
+    pub fn permutation(&self) -> &Vec<usize> {
+        &self.permutation
+    }
+
+    /// Returns the permutation matrix `P`.
+    pub fn p(&self) -> Matrix<f64> {
+        let size = self.permutation.len();
+        let mut out = Matrix::zero(size, size);
+        for (j, &i) in self.permutation.iter().enumerate() {
+            out.entries[i * size + j] = 1.0;
+        }
+        out
+    }
+
+    /// Returns the numerical rank, i.e. the number of diagonal entries of `R` that aren't
+    /// negligible compared to the largest one. This is only meaningful for decompositions
+    /// computed with [`Matrix::qr_with_column_pivoting()`].
+    pub fn rank(&self) -> usize {
+        let size = self.r.height().min(self.r.width());
+        let diagonal: Vec<f64> = (0..size)
+            .map(|i| self.r.entries[i * self.r.width + i].abs())
+            .collect();
+        let largest = diagonal.iter().fold(0.0, |acc: f64, x| acc.max(*x));
+        let tolerance = largest * f64::EPSILON * self.r.height().max(self.r.width()) as f64;
+        diagonal.iter().filter(|x| **x > tolerance).count()
+    }
+}
@@ -0,0 +1,445 @@
+use crate::{errors::MatrixError, Matrix};
+
+/// Maximum number of QR steps spent on a single singular value.
+const MAX_ITERATIONS: usize = 75;
+
+/// Thin singular value decomposition `A = UΣVᵀ` of a real `m×n` matrix, computed by
+/// [`Matrix::svd()`]. With `k = min(m, n)`, `U` is `m×k`, `Vᵀ` is `k×n`, both with orthonormal
+/// columns and rows respectively, and `Σ` is given by the `k` singular values in decreasing order.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Svd {
+    pub(crate) u: Matrix<f64>,
+    pub(crate) singular_values: Vec<f64>,
+    pub(crate) vt: Matrix<f64>,
+}
+
+impl Matrix<f64> {
+    /// Returns the singular value decomposition of a real matrix of any shape.
+    /// The matrix is first reduced to bidiagonal form by Householder reflections
+    /// (Golub–Kahan bidiagonalization), and then the implicitly shifted QR algorithm is run on it.
+    /// It'll throw an error if the algorithm doesn't converge.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![3.0, 0.0], vec![4.0, 5.0]]).unwrap();
+    /// let svd = m.svd().unwrap();
+    /// let s = Matrix::diagonal_matrix(svd.singular_values().clone());
+    /// let n = svd.u().clone() * s * svd.vt().clone();
+    /// assert!((svd.singular_values()[0] - 45f64.sqrt()).abs() < 1e-12);
+    /// assert!((svd.singular_values()[1] - 5f64.sqrt()).abs() < 1e-12);
+    /// assert!(n.rows().iter().flatten().zip(m.rows().iter().flatten()).all(|(x, y)| (x - y).abs() < 1e-12));
+    /// ```
+    pub fn svd(&self) -> Result<Svd, MatrixError> {
+        // The algorithm needs at least as many rows as columns, so we work with the transpose
+        // otherwise, and then swap U and V.
+        if self.height() >= self.width() {
+            let (u, singular_values, v) = golub_kahan(self.clone(), true)?;
+            Ok(Svd {
+                u,
+                singular_values,
+                vt: v.transpose(),
+            })
+        } else {
+            let (v, singular_values, u) = golub_kahan(self.transpose(), true)?;
+            Ok(Svd {
+                u,
+                singular_values,
+                vt: v.transpose(),
+            })
+        }
+    }
+
+    /// Returns the singular values of a real matrix of any shape in decreasing order.
+    /// This is cheaper than [`svd`](Self::svd()) as the singular vectors aren't computed.
+    /// It'll throw an error if the algorithm doesn't converge.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![0.0, 2.0, 0.0], vec![-3.0, 0.0, 0.0]]).unwrap();
+    /// assert_eq!(m.singular_values(), Ok(vec![3.0, 2.0]));
+    /// ```
+    pub fn singular_values(&self) -> Result<Vec<f64>, MatrixError> {
+        let a = if self.height() >= self.width() {
+            self.clone()
+        } else {
+            self.transpose()
+        };
+        golub_kahan(a, false).map(|(_, singular_values, _)| singular_values)
+    }
+}
+
+impl Svd {
+    /// Returns the matrix `U` of left singular vectors.
+    pub fn u(&self) -> &Matrix<f64> {
+        &self.u
+    }
+
+    /// Returns the singular values in decreasing order.
+    pub fn singular_values(&self) -> &Vec<f64> {
+        &self.singular_values
+    }
+
+    /// Returns the matrix `Vᵀ`, whose rows are the right singular vectors.
+    pub fn vt(&self) -> &Matrix<f64> {
+        &self.vt
+    }
+}
+
+/// The matrices `U` and `V` together with the singular values, as found by [`golub_kahan`].
+type Decomposition = (Matrix<f64>, Vec<f64>, Matrix<f64>);
+
+/// What to do next in the main loop of [`golub_kahan`].
+enum Step {
+    /// The last singular value is negligible, so it's deflated.
+    Deflate,
+    /// Some singular value is negligible, so the problem is split there.
+    Split,
+    /// Nothing is negligible, so a QR step is performed.
+    Qr,
+    /// The last superdiagonal element is negligible, so a singular value has converged.
+    Converged,
+}
+
+/// Computes the singular values (and optionally the singular vectors `U` and `V`) of an
+/// `m×n` matrix with `m >= n`. `U` is `m×n` and `V` is `n×n`, they're empty if
+/// `want_vectors` is false. The matrix is destroyed in the process.
+/// This is adapted from the public domain JAMA library, which in turn is based on LINPACK.
+fn golub_kahan(mut a: Matrix<f64>, want_vectors: bool) -> Result<Decomposition, MatrixError> {
+    // All of A, U and V have n columns, so their entries are at i * n + j.
+    let m = a.height();
+    let n = a.width();
+    let empty = Matrix::zero(0, 0);
+    if n == 0 {
+        let u = if want_vectors {
+            Matrix::zero(m, 0)
+        } else {
+            empty
+        };
+        return Ok((u, Vec::new(), Matrix::zero(0, 0)));
+    }
+    let mut s = vec![0.0; n.min(m + 1)];
+    let mut u = if want_vectors {
+        Matrix::zero(m, n)
+    } else {
+        empty.clone()
+    };
+    let mut v = if want_vectors {
+        Matrix::zero(n, n)
+    } else {
+        empty
+    };
+    let mut e = vec![0.0; n];
+    let mut work = vec![0.0; m];
+
+    // Reduce A to bidiagonal form, storing the diagonal elements
+    // in s and the superdiagonal elements in e.
+    let nct = (m - 1).min(n);
+    let nrt = n.saturating_sub(2).min(m);
+    for k in 0..nct.max(nrt) {
+        if k < nct {
+            // Compute the transformation for the k-th column and
+            // place the k-th diagonal in s[k].
+            s[k] = a
+                .row_slices()
+                .skip(k)
+                .fold(0.0, |acc: f64, row| acc.hypot(row[k]));
+            if s[k] != 0.0 {
+                if a.entries[k * n + k] < 0.0 {
+                    s[k] = -s[k];
+                }
+                for row in a.row_slices_mut().skip(k) {
+                    row[k] /= s[k];
+                }
+                a.entries[k * n + k] += 1.0;
+            }
+            s[k] = -s[k];
+        }
+        for j in (k + 1)..n {
+            if k < nct && s[k] != 0.0 {
+                // Apply the transformation.
+                let t: f64 = a.row_slices().skip(k).map(|row| row[k] * row[j]).sum();
+                let t = -t / a.entries[k * n + k];
+                for row in a.row_slices_mut().skip(k) {
+                    row[j] += t * row[k];
+                }
+            }
+            // Place the k-th row of A into e for the
+            // subsequent calculation of the row transformation.
+            e[j] = a.entries[k * n + j];
+        }
+        if want_vectors && k < nct {
+            // Place the transformation in U for subsequent back multiplication.
+            for (u_row, row) in u.row_slices_mut().zip(a.row_slices()).skip(k) {
+                u_row[k] = row[k];
+            }
+        }
+        if k < nrt {
+            // Compute the k-th row transformation and place the
+            // k-th superdiagonal in e[k].
+            e[k] = e.iter().skip(k + 1).fold(0.0, |acc: f64, x| acc.hypot(*x));
+            if e[k] != 0.0 {
+                if e[k + 1] < 0.0 {
+                    e[k] = -e[k];
+                }
+                let divisor = e[k];
+                for x in e.iter_mut().skip(k + 1) {
+                    *x /= divisor;
+                }
+                e[k + 1] += 1.0;
+            }
+            e[k] = -e[k];
+            if k + 1 < m && e[k] != 0.0 {
+                // Apply the transformation.
+                for (w, row) in work.iter_mut().zip(a.row_slices()).skip(k + 1) {
+                    *w = e.iter().zip(row).skip(k + 1).map(|(x, y)| x * y).sum();
+                }
+                for j in (k + 1)..n {
+                    let t = -e[j] / e[k + 1];
+                    for (row, w) in a.row_slices_mut().zip(&work).skip(k + 1) {
+                        row[j] += t * w;
+                    }
+                }
+            }
+            if want_vectors {
+                // Place the transformation in V for subsequent back multiplication.
+                for (row, x) in v.row_slices_mut().zip(&e).skip(k + 1) {
+                    row[k] = *x;
+                }
+            }
+        }
+    }
+
+    // Set up the final bidiagonal matrix of order p.
+    let mut p = n.min(m + 1);
+    if nct < n {
+        s[nct] = a.entries[nct * n + nct];
+    }
+    if m < p {
+        s[p - 1] = 0.0;
+    }
+    if nrt + 1 < p {
+        e[nrt] = a.entries[nrt * n + p - 1];
+    }
+    e[p - 1] = 0.0;
+
+    if want_vectors {
+        // Generate U.
+        for j in nct..n {
+            for row in u.row_slices_mut() {
+                row[j] = 0.0;
+            }
+            u.entries[j * n + j] = 1.0;
+        }
+        for k in (0..nct).rev() {
+            if s[k] != 0.0 {
+                for j in (k + 1)..n {
+                    let t: f64 = u.row_slices().skip(k).map(|row| row[k] * row[j]).sum();
+                    let t = -t / u.entries[k * n + k];
+                    for row in u.row_slices_mut().skip(k) {
+                        row[j] += t * row[k];
+                    }
+                }
+                for row in u.row_slices_mut().skip(k) {
+                    row[k] = -row[k];
+                }
+                u.entries[k * n + k] += 1.0;
+                for row in u.row_slices_mut().take(k) {
+                    row[k] = 0.0;
+                }
+            } else {
+                for row in u.row_slices_mut() {
+                    row[k] = 0.0;
+                }
+                u.entries[k * n + k] = 1.0;
+            }
+        }
+
+        // Generate V.
+        for k in (0..n).rev() {
+            if k < nrt && e[k] != 0.0 {
+                for j in (k + 1)..n {
+                    let t: f64 = v.row_slices().skip(k + 1).map(|row| row[k] * row[j]).sum();
+                    let t = -t / v.entries[(k + 1) * n + k];
+                    for row in v.row_slices_mut().skip(k + 1) {
+                        row[j] += t * row[k];
+                    }
+                }
+            }
+            for row in v.row_slices_mut() {
+                row[k] = 0.0;
+            }
+            v.entries[k * n + k] = 1.0;
+        }
+    }
+
+    // Rotates the columns i and j of a matrix by the given cosine and sine.
+    let rotate = |matrix: &mut Matrix<f64>, i: usize, j: usize, cs: f64, sn: f64| {
+        for row in matrix.row_slices_mut() {
+            let t = cs * row[i] + sn * row[j];
+            row[j] = cs * row[j] - sn * row[i];
+            row[i] = t;
+        }
+    };
+
+    // Main iteration loop for the singular values.
+    let last = p - 1;
+    let mut iterations = 0;
+    let eps = f64::EPSILON;
+    let tiny = 2f64.powi(-966);
+    while p > 0 {
+        if iterations == MAX_ITERATIONS {
+            return Err(MatrixError::NoConvergence);
+        }
+
+        // Look for negligible elements in s and e. Afterwards, the block to work on
+        // starts at k and ends at p - 1.
+        let mut k = p - 1;
+        while k > 0 {
+            if e[k - 1].abs() <= tiny + eps * (s[k - 1].abs() + s[k].abs()) {
+                e[k - 1] = 0.0;
+                break;
+            }
+            k -= 1;
+        }
+        let step = if k == p - 1 {
+            Step::Converged
+        } else {
+            let mut ks = p;
+            while ks > k {
+                let t = e[ks - 1].abs() + if ks != k + 1 { e[ks - 2].abs() } else { 0.0 };
+                if s[ks - 1].abs() <= tiny + eps * t {
+                    s[ks - 1] = 0.0;
+                    break;
+                }
+                ks -= 1;
+            }
+            if ks == k {
+                Step::Qr
+            } else if ks == p {
+                Step::Deflate
+            } else {
+                k = ks;
+                Step::Split
+            }
+        };
+
+        match step {
+            Step::Deflate => {
+                let mut f = e[p - 2];
+                e[p - 2] = 0.0;
+                for j in (k..=(p - 2)).rev() {
+                    let t = s[j].hypot(f);
+                    let cs = s[j] / t;
+                    let sn = f / t;
+                    s[j] = t;
+                    if j != k {
+                        f = -sn * e[j - 1];
+                        e[j - 1] *= cs;
+                    }
+                    if want_vectors {
+                        rotate(&mut v, j, p - 1, cs, sn);
+                    }
+                }
+            }
+            Step::Split => {
+                let mut f = e[k - 1];
+                e[k - 1] = 0.0;
+                for j in k..p {
+                    let t = s[j].hypot(f);
+                    let cs = s[j] / t;
+                    let sn = f / t;
+                    s[j] = t;
+                    f = -sn * e[j];
+                    e[j] *= cs;
+                    if want_vectors {
+                        rotate(&mut u, j, k - 1, cs, sn);
+                    }
+                }
+            }
+            Step::Qr => {
+                // Calculate the shift.
+                let scale = s[p - 1]
+                    .abs()
+                    .max(s[p - 2].abs())
+                    .max(e[p - 2].abs())
+                    .max(s[k].abs())
+                    .max(e[k].abs());
+                let sp = s[p - 1] / scale;
+                let spm1 = s[p - 2] / scale;
+                let epm1 = e[p - 2] / scale;
+                let sk = s[k] / scale;
+                let ek = e[k] / scale;
+                let b = ((spm1 + sp) * (spm1 - sp) + epm1 * epm1) / 2.0;
+                let c = (sp * epm1) * (sp * epm1);
+                let mut shift = 0.0;
+                if b != 0.0 || c != 0.0 {
+                    shift = (b * b + c).sqrt();
+                    if b < 0.0 {
+                        shift = -shift;
+                    }
+                    shift = c / (b + shift);
+                }
+                let mut f = (sk + sp) * (sk - sp) + shift;
+                let mut g = sk * ek;
+
+                // Chase zeros.
+                for j in k..(p - 1) {
+                    let t = f.hypot(g);
+                    let cs = f / t;
+                    let sn = g / t;
+                    if j != k {
+                        e[j - 1] = t;
+                    }
+                    f = cs * s[j] + sn * e[j];
+                    e[j] = cs * e[j] - sn * s[j];
+                    g = sn * s[j + 1];
+                    s[j + 1] *= cs;
+                    if want_vectors {
+                        rotate(&mut v, j, j + 1, cs, sn);
+                    }
+                    let t = f.hypot(g);
+                    let cs = f / t;
+                    let sn = g / t;
+                    s[j] = t;
+                    f = cs * e[j] + sn * s[j + 1];
+                    s[j + 1] = -sn * e[j] + cs * s[j + 1];
+                    g = sn * e[j + 1];
+                    e[j + 1] *= cs;
+                    if want_vectors && j < m - 1 {
+                        rotate(&mut u, j, j + 1, cs, sn);
+                    }
+                }
+                e[p - 2] = f;
+                iterations += 1;
+            }
+            Step::Converged => {
+                // Make the singular value positive.
+                if s[k] <= 0.0 {
+                    s[k] = if s[k] < 0.0 { -s[k] } else { 0.0 };
+                    if want_vectors {
+                        for row in v.row_slices_mut() {
+                            row[k] = -row[k];
+                        }
+                    }
+                }
+                // Move it to its place in decreasing order.
+                while k < last && s[k] < s[k + 1] {
+                    s.swap(k, k + 1);
+                    if want_vectors {
+                        for row in v.row_slices_mut() {
+                            row.swap(k, k + 1);
+                        }
+                        for row in u.row_slices_mut() {
+                            row.swap(k, k + 1);
+                        }
+                    }
+                    k += 1;
+                }
+                iterations = 0;
+                p -= 1;
+            }
+        }
+    }
+    s.truncate(n);
+    Ok((u, s, v))
+}
@@ -0,0 +1,127 @@
+use crate::{errors::MatrixError, Matrix};
+
+/// Relative tolerance used when checking if a matrix is symmetric.
+const SYMMETRY_TOLERANCE: f64 = 1e-10;
+
+/// Maximum number of Jacobi sweeps before giving up.
+const MAX_SWEEPS: usize = 50;
+
+/// Eigendecomposition `A = VΛVᵀ` of a real symmetric matrix, computed by
+/// [`Matrix::symmetric_eigen()`]. The eigenvalues are real and sorted in increasing order,
+/// and the eigenvectors form an orthonormal basis.
+#[derive(PartialEq, Debug, Clone)]
+pub struct SymmetricEigen {
+    pub(crate) eigenvalues: Vec<f64>,
+    pub(crate) eigenvectors: Matrix<f64>,
+}
+
+impl Matrix<f64> {
+    /// Returns true if a square matrix is symmetric up to a small relative tolerance.
+    pub(crate) fn is_nearly_symmetric(&self) -> bool {
+        let scale = self
+            .entries
+            .iter()
+            .fold(0.0, |acc: f64, entry| acc.max(entry.abs()));
+        let n = self.width;
+        self.is_square()
+            && self.row_slices().enumerate().all(|(i, row)| {
+                row.iter().enumerate().skip(i + 1).all(|(j, entry)| {
+                    (entry - self.entries[j * n + i]).abs() <= SYMMETRY_TOLERANCE * scale
+                })
+            })
+    }
+
+    /// Returns the eigendecomposition of a real symmetric matrix using the cyclic Jacobi method.
+    /// Unlike [`eigenvectors`](Self::eigenvectors()), the eigenvalues are guaranteed to be real
+    /// and the eigenvectors to be orthonormal, even for repeated eigenvalues.
+    /// It'll throw an error if the provided matrix isn't square or symmetric (up to a small
+    /// relative tolerance), or if the algorithm doesn't converge.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![2.0, 1.0], vec![1.0, 2.0]]).unwrap();
+    /// let eigen = m.symmetric_eigen().unwrap();
+    /// let v = eigen.eigenvectors();
+    /// assert!((eigen.eigenvalues()[0] - 1.0).abs() < 1e-12);
+    /// assert!((eigen.eigenvalues()[1] - 3.0).abs() < 1e-12);
+    /// assert!((v.rows()[0][1] - v.rows()[1][1]).abs() < 1e-12);
+    /// ```
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare);
+        }
+        if !self.is_nearly_symmetric() {
+            return Err(MatrixError::NotSymmetric);
+        }
+        let n = self.height();
+        let mut a = self.clone();
+        let mut v = Self::identity(n);
+        let norm = a.entries.iter().map(|entry| entry * entry).sum::<f64>();
+
+        let mut converged = false;
+        for _ in 0..MAX_SWEEPS {
+            let off_diagonal = (0..n)
+                .flat_map(|i| ((i + 1)..n).map(move |j| (i, j)))
+                .map(|(i, j)| 2.0 * a.entries[i * n + j] * a.entries[i * n + j])
+                .sum::<f64>();
+            if off_diagonal <= f64::EPSILON * f64::EPSILON * norm {
+                converged = true;
+                break;
+            }
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    let entry = a.entries[p * n + q];
+                    if entry == 0.0 {
+                        continue;
+                    }
+                    // Choose the rotation angle so that the (p, q) entry vanishes.
+                    let theta = (a.entries[q * n + q] - a.entries[p * n + p]) / (2.0 * entry);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                    let c = 1.0 / (t * t + 1.0).sqrt();
+                    let s = t * c;
+                    for row in a.row_slices_mut().chain(v.row_slices_mut()) {
+                        let (x, y) = (row[p], row[q]);
+                        row[p] = c * x - s * y;
+                        row[q] = s * x + c * y;
+                    }
+                    let (upper, lower) = a.row_pair_mut(p, q);
+                    for (x, y) in upper.iter_mut().zip(lower.iter_mut()) {
+                        let (old_x, old_y) = (*x, *y);
+                        *x = c * old_x - s * old_y;
+                        *y = s * old_x + c * old_y;
+                    }
+                    // This is zero up to rounding errors, which we get rid of.
+                    a.entries[p * n + q] = 0.0;
+                    a.entries[q * n + p] = 0.0;
+                }
+            }
+        }
+        if !converged {
+            return Err(MatrixError::NoConvergence);
+        }
+
+        // Sort the eigenvalues, moving the eigenvectors along.
+        let mut order: Vec<usize> = (0..n).collect();
+        let diagonal: Vec<f64> = a.entries.iter().step_by(n + 1).copied().collect();
+        order.sort_by(|&i, &j| diagonal[i].total_cmp(&diagonal[j]));
+        let eigenvalues = order.iter().map(|&i| diagonal[i]).collect();
+        let eigenvectors = Self::from_fn(n, n, |i, j| v.entries[i * n + order[j]]);
+        Ok(SymmetricEigen {
+            eigenvalues,
+            eigenvectors,
+        })
+    }
+}
+
+impl SymmetricEigen {
+    /// Returns the eigenvalues in increasing order.
+    pub fn eigenvalues(&self) -> &Vec<f64> {
+        &self.eigenvalues
+    }
+
+    /// Returns the orthonormal matrix whose columns are the eigenvectors,
+    /// in the same order as the eigenvalues.
+    pub fn eigenvectors(&self) -> &Matrix<f64> {
+        &self.eigenvectors
+    }
+}
@@ -0,0 +1,439 @@
+use crate::{decompositions::LuDecomposition, errors::MatrixError, Matrix};
+use num::complex::Complex64;
+
+/// Maximum number of QR iterations spent on a single eigenvalue.
+const MAX_ITERATIONS: usize = 30;
+
+/// Number of steps of inverse iteration used for each eigenvector.
+const INVERSE_ITERATIONS: usize = 3;
+
+impl Matrix<f64> {
+    /// Returns the (possibly complex) eigenvalues of a square real matrix.
+    /// The matrix is first reduced to upper Hessenberg form, and then the Francis
+    /// double shifted QR algorithm is run on it. Complex eigenvalues come in conjugate pairs,
+    /// and the order matches the one used in [`eigenvectors`](Self::eigenvectors()).
+    /// It'll throw an error if the provided matrix isn't square, or if the algorithm
+    /// doesn't converge.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// use num::complex::Complex64;
+    /// let m = Matrix::from(vec![vec![0.0, -1.0], vec![1.0, 0.0]]).unwrap();
+    /// let eigenvalues = m.eigenvalues().unwrap();
+    /// assert!(eigenvalues.contains(&Complex64::new(0.0, 1.0)));
+    /// assert!(eigenvalues.contains(&Complex64::new(0.0, -1.0)));
+    /// ```
+    pub fn eigenvalues(&self) -> Result<Vec<Complex64>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare);
+        }
+        let mut a = self.clone();
+        to_hessenberg(&mut a);
+        hessenberg_qr(&mut a)
+    }
+
+    /// Returns the eigenvalues of a square real matrix together with a matrix whose columns
+    /// are the corresponding eigenvectors, normalized to unit length. The eigenvalues are
+    /// found using [`eigenvalues`](Self::eigenvalues()), and each eigenvector by inverse iteration.
+    /// For repeated eigenvalues, each eigenvector is kept orthogonal to the ones already found
+    /// for the same eigenvalue, so that they're linearly independent.
+    /// Defective matrices don't have enough eigenvectors, in which case a repeated eigenvalue
+    /// is only returned as many times as it has independent eigenvectors, so there are fewer
+    /// columns than the size of the matrix. Use [`eigenvalues`](Self::eigenvalues()) to get all
+    /// of them with their multiplicities.
+    /// It'll throw an error if the provided matrix isn't square, or if the algorithm
+    /// doesn't converge.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![2.0, 1.0], vec![1.0, 2.0]]).unwrap();
+    /// let (eigenvalues, eigenvectors) = m.eigenvectors().unwrap();
+    /// for (i, lambda) in eigenvalues.iter().enumerate() {
+    ///     let v = eigenvectors.columns()[i].clone();
+    ///     assert!((v[0] * 2.0 + v[1] - lambda * v[0]).norm() < 1e-9);
+    /// }
+    ///
+    /// let m = Matrix::from(vec![vec![2.0, 1.0], vec![0.0, 2.0]]).unwrap();
+    /// let (eigenvalues, eigenvectors) = m.eigenvectors().unwrap();
+    /// assert_eq!(eigenvalues.len(), 1);
+    /// assert_eq!(eigenvectors.width(), 1);
+    /// ```
+    pub fn eigenvectors(&self) -> Result<(Vec<Complex64>, Matrix<Complex64>), MatrixError> {
+        let n = self.height();
+        let scale = self
+            .entries
+            .iter()
+            .fold(0.0, |acc: f64, entry| acc.max(entry.abs()))
+            .max(1.0);
+
+        let mut eigenvalues: Vec<Complex64> = Vec::new();
+        let mut columns: Vec<Vec<Complex64>> = Vec::new();
+        for lambda in self.eigenvalues()? {
+            // Eigenvectors already found for (numerically) the same eigenvalue.
+            let found: Vec<&Vec<Complex64>> = eigenvalues
+                .iter()
+                .zip(&columns)
+                .filter(|(other, _)| (*other - lambda).norm() <= scale * 1e-6)
+                .map(|(_, column)| column)
+                .collect();
+            // The shift stays the same, so the system is only decomposed once.
+            let lu = self.shifted_lu(lambda, scale)?;
+            let mut x = start_vector(n, &found);
+            for _ in 0..INVERSE_ITERATIONS {
+                x = solve_complex(&lu, &x)?;
+                orthogonalize(&mut x, &found);
+                normalize(&mut x);
+            }
+            // If the eigenvalue is defective, what's left after removing the eigenvectors
+            // already found isn't an eigenvector.
+            if self.residual(lambda, &x) <= scale * 1e-6 {
+                eigenvalues.push(lambda);
+                columns.push(x);
+            }
+        }
+
+        let k = columns.len();
+        Ok((eigenvalues, Matrix::from_fn(n, k, |i, j| columns[j][i])))
+    }
+
+    /// Returns the LU decomposition of `A - λI` for a complex shift `λ = a + bi` slightly
+    /// perturbed from the provided eigenvalue, so that it isn't singular. If it still is,
+    /// the perturbation is increased. It's decomposed as the equivalent real matrix of twice
+    /// the size, i.e.
+    /// ⌈A - aI,   bI⌉
+    /// ⌊  -bI, A - aI⌋
+    /// see [`solve_complex`] for using it.
+    fn shifted_lu(
+        &self,
+        eigenvalue: Complex64,
+        scale: f64,
+    ) -> Result<LuDecomposition<f64>, MatrixError> {
+        let n = self.height();
+        let m = 2 * n;
+        for perturbation in [1e-10, 1e-8, 1e-6] {
+            let shift = eigenvalue + scale * perturbation;
+            let mut system = Matrix::zero(m, m);
+            for (i, row) in self.row_slices().enumerate() {
+                for (j, entry) in row.iter().enumerate() {
+                    system.entries[i * m + j] = *entry;
+                    system.entries[(i + n) * m + j + n] = *entry;
+                }
+                system.entries[i * m + i] -= shift.re;
+                system.entries[(i + n) * m + i + n] -= shift.re;
+                system.entries[i * m + i + n] = shift.im;
+                system.entries[(i + n) * m + i] = -shift.im;
+            }
+            let lu = system.into_lu();
+            if lu.rank() == m {
+                return Ok(lu);
+            }
+        }
+        Err(MatrixError::NoConvergence)
+    }
+
+    /// Returns the norm of `Ax - λx` for a complex vector `x` and a complex `λ`.
+    fn residual(&self, lambda: Complex64, x: &[Complex64]) -> f64 {
+        self.row_slices()
+            .zip(x)
+            .map(|(row, x_i)| {
+                let product: Complex64 = row.iter().zip(x).map(|(a, x_j)| x_j * a).sum();
+                (product - lambda * x_i).norm_sqr()
+            })
+            .sum::<f64>()
+            .sqrt()
+    }
+}
+
+/// Solves `(A - λI)x = b` for a complex right hand side, where the provided LU decomposition
+/// is the one of the real matrix of twice the size returned by
+/// [`shifted_lu`](Matrix::shifted_lu()), i.e.
+/// ⌈A - aI,   bI⌉ ⌈Re x⌉   ⌈Re b⌉
+/// ⌊  -bI, A - aI⌋ ⌊Im x⌋ = ⌊Im b⌋
+fn solve_complex(
+    lu: &LuDecomposition<f64>,
+    rhs: &[Complex64],
+) -> Result<Vec<Complex64>, MatrixError> {
+    let n = rhs.len();
+    let mut b = Matrix::zero(2 * n, 1);
+    for (i, entry) in rhs.iter().enumerate() {
+        b.entries[i] = entry.re;
+        b.entries[i + n] = entry.im;
+    }
+    let x = lu.solve(&b)?;
+    Ok((0..n)
+        .map(|i| Complex64::new(x.entries[i], x.entries[i + n]))
+        .collect())
+}
+
+/// Returns a unit vector to start inverse iteration from, away from the span of the provided
+/// orthonormal vectors. It's the vector of all ones unless that's mostly in the span, in which
+/// case the standard basis vector furthest from the span is used.
+fn start_vector(n: usize, found: &[&Vec<Complex64>]) -> Vec<Complex64> {
+    let norm_sqr = |x: &[Complex64]| x.iter().map(|entry| entry.norm_sqr()).sum::<f64>();
+    let mut x = vec![Complex64::new(1.0 / (n as f64).sqrt(), 0.0); n];
+    orthogonalize(&mut x, found);
+    if norm_sqr(&x) < 0.5 {
+        for i in 0..n {
+            let mut e = vec![Complex64::new(0.0, 0.0); n];
+            e[i] = Complex64::new(1.0, 0.0);
+            orthogonalize(&mut e, found);
+            if norm_sqr(&e) > norm_sqr(&x) {
+                x = e;
+            }
+        }
+    }
+    normalize(&mut x);
+    x
+}
+
+/// Removes the components of a complex vector along the provided orthonormal vectors,
+/// i.e. one step of (modified) Gram–Schmidt.
+fn orthogonalize(x: &mut [Complex64], found: &[&Vec<Complex64>]) {
+    for v in found {
+        let projection: Complex64 = v.iter().zip(x.iter()).map(|(a, b)| a.conj() * b).sum();
+        for (entry, v_entry) in x.iter_mut().zip(v.iter()) {
+            *entry -= projection * v_entry;
+        }
+    }
+}
+
+/// Scales a complex vector to unit length, rotating it so that its largest entry is real.
+fn normalize(x: &mut [Complex64]) {
+    let largest = x.iter().fold(Complex64::new(0.0, 0.0), |acc, entry| {
+        if entry.norm() > acc.norm() {
+            *entry
+        } else {
+            acc
+        }
+    });
+    if largest.norm() == 0.0 {
+        return;
+    }
+    let phase = largest / largest.norm();
+    let norm = x.iter().map(|entry| entry.norm_sqr()).sum::<f64>().sqrt();
+    for entry in x.iter_mut() {
+        *entry = *entry / phase / norm;
+    }
+}
+
+/// Reduces a square matrix to upper Hessenberg form by similarity transforms,
+/// using Gaussian elimination with pivoting.
+fn to_hessenberg(a: &mut Matrix<f64>) {
+    let n = a.height();
+    for m in 1..n.saturating_sub(1) {
+        // Find the pivot in the column below the subdiagonal.
+        let mut x: f64 = 0.0;
+        let mut p = m;
+        for (j, row) in a.row_slices().enumerate().skip(m) {
+            if row[m - 1].abs() > x.abs() {
+                x = row[m - 1];
+                p = j;
+            }
+        }
+        if p != m {
+            a.swap_rows(p, m);
+            for row in a.row_slices_mut() {
+                row.swap(p, m);
+            }
+        }
+        if x == 0.0 {
+            continue;
+        }
+        for i in (m + 1)..n {
+            let y = a.entries[i * n + m - 1] / x;
+            if y == 0.0 {
+                continue;
+            }
+            a.entries[i * n + m - 1] = 0.0;
+            let (pivot_row, row) = a.row_pair_mut(m, i);
+            for (entry, pivot_entry) in row.iter_mut().zip(&*pivot_row).skip(m) {
+                *entry -= y * pivot_entry;
+            }
+            for row in a.row_slices_mut() {
+                row[m] += y * row[i];
+            }
+        }
+    }
+}
+
+/// Returns `|a|` with the sign of `b`.
+fn sign(a: f64, b: f64) -> f64 {
+    if b >= 0.0 {
+        a.abs()
+    } else {
+        -a.abs()
+    }
+}
+
+/// Finds all the eigenvalues of an upper Hessenberg matrix using the Francis
+/// double shifted QR algorithm. The matrix is destroyed in the process.
+fn hessenberg_qr(a: &mut Matrix<f64>) -> Result<Vec<Complex64>, MatrixError> {
+    let n = a.height();
+    let mut out = vec![Complex64::new(0.0, 0.0); n];
+    let mut norm: f64 = 0.0;
+    for (i, row) in a.row_slices().enumerate() {
+        for entry in row.iter().skip(i.saturating_sub(1)) {
+            norm += entry.abs();
+        }
+    }
+
+    // The rows and columns 0..active are the ones yet to be deflated.
+    let mut active = n;
+    let mut shift: f64 = 0.0;
+    let mut iterations = 0;
+    while active > 0 {
+        let last = active - 1;
+        // Look for a single small subdiagonal element to split the matrix.
+        let mut l = 0;
+        for k in (1..=last).rev() {
+            let mut s = a.entries[(k - 1) * n + k - 1].abs() + a.entries[k * n + k].abs();
+            if s == 0.0 {
+                s = norm;
+            }
+            if a.entries[k * n + k - 1].abs() + s == s {
+                a.entries[k * n + k - 1] = 0.0;
+                l = k;
+                break;
+            }
+        }
+
+        let mut x = a.entries[last * n + last];
+        if l == last {
+            // One root found.
+            out[last] = Complex64::new(x + shift, 0.0);
+            active -= 1;
+            iterations = 0;
+            continue;
+        }
+        let mut y = a.entries[(last - 1) * n + last - 1];
+        let mut w = a.entries[last * n + last - 1] * a.entries[(last - 1) * n + last];
+        if l == last - 1 {
+            // Two roots found.
+            let p = 0.5 * (y - x);
+            let q = p * p + w;
+            let mut z = q.abs().sqrt();
+            x += shift;
+            if q >= 0.0 {
+                z = p + sign(z, p);
+                let second = if z != 0.0 { x - w / z } else { x + z };
+                out[last - 1] = Complex64::new(x + z, 0.0);
+                out[last] = Complex64::new(second, 0.0);
+            } else {
+                out[last - 1] = Complex64::new(x + p, -z);
+                out[last] = Complex64::new(x + p, z);
+            }
+            active -= 2;
+            iterations = 0;
+            continue;
+        }
+
+        if iterations == MAX_ITERATIONS {
+            return Err(MatrixError::NoConvergence);
+        }
+        if iterations == 10 || iterations == 20 {
+            // Exceptional shift.
+            shift += x;
+            for (i, row) in a.row_slices_mut().enumerate().take(active) {
+                row[i] -= x;
+            }
+            let s =
+                a.entries[last * n + last - 1].abs() + a.entries[(last - 1) * n + last - 2].abs();
+            x = 0.75 * s;
+            y = x;
+            w = -0.4375 * s * s;
+        }
+        iterations += 1;
+
+        // Form the shift and look for two consecutive small subdiagonal elements.
+        let (mut p, mut q, mut r);
+        let mut m = last - 2;
+        loop {
+            let z = a.entries[m * n + m];
+            r = x - z;
+            let s = y - z;
+            p = (r * s - w) / a.entries[(m + 1) * n + m] + a.entries[m * n + m + 1];
+            q = a.entries[(m + 1) * n + m + 1] - z - r - s;
+            r = a.entries[(m + 2) * n + m + 1];
+            let s = p.abs() + q.abs() + r.abs();
+            p /= s;
+            q /= s;
+            r /= s;
+            if m == l {
+                break;
+            }
+            let u = a.entries[m * n + m - 1].abs() * (q.abs() + r.abs());
+            let v = p.abs()
+                * (a.entries[(m - 1) * n + m - 1].abs()
+                    + z.abs()
+                    + a.entries[(m + 1) * n + m + 1].abs());
+            if u + v == v {
+                break;
+            }
+            m -= 1;
+        }
+        for i in (m + 2)..=last {
+            a.entries[i * n + i - 2] = 0.0;
+            if i != m + 2 {
+                a.entries[i * n + i - 3] = 0.0;
+            }
+        }
+
+        // Double QR step on rows l..=last and columns m..=last.
+        for k in m..last {
+            let mut scale = 0.0;
+            if k != m {
+                p = a.entries[k * n + k - 1];
+                q = a.entries[(k + 1) * n + k - 1];
+                r = if k != last - 1 {
+                    a.entries[(k + 2) * n + k - 1]
+                } else {
+                    0.0
+                };
+                scale = p.abs() + q.abs() + r.abs();
+                if scale != 0.0 {
+                    p /= scale;
+                    q /= scale;
+                    r /= scale;
+                }
+            }
+            let s = sign((p * p + q * q + r * r).sqrt(), p);
+            if s == 0.0 {
+                continue;
+            }
+            if k == m {
+                if l != m {
+                    a.entries[k * n + k - 1] = -a.entries[k * n + k - 1];
+                }
+            } else {
+                a.entries[k * n + k - 1] = -s * scale;
+            }
+            p += s;
+            let x = p / s;
+            let y = q / s;
+            let z = r / s;
+            q /= p;
+            r /= p;
+            // Row modification.
+            for j in k..active {
+                let mut p = a.entries[k * n + j] + q * a.entries[(k + 1) * n + j];
+                if k != last - 1 {
+                    p += r * a.entries[(k + 2) * n + j];
+                    a.entries[(k + 2) * n + j] -= p * z;
+                }
+                a.entries[(k + 1) * n + j] -= p * y;
+                a.entries[k * n + j] -= p * x;
+            }
+            // Column modification.
+            for row in a.row_slices_mut().take(last.min(k + 3) + 1).skip(l) {
+                let mut p = x * row[k] + y * row[k + 1];
+                if k != last - 1 {
+                    p += z * row[k + 2];
+                    row[k + 2] -= p * r;
+                }
+                row[k + 1] -= p * q;
+                row[k] -= p;
+            }
+        }
+    }
+    Ok(out)
+}
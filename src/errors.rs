@@ -13,6 +13,24 @@ pub enum MatrixError {
     Singular,
     /// Provided array has unequal rows.
     UnequalRows,
+    /// Provided matrix isn't symmetric.
+    NotSymmetric,
+    /// Provided matrix isn't positive definite.
+    NotPositiveDefinite,
+    /// An iterative algorithm didn't converge.
+    NoConvergence,
+    /// Provided matrices have incompatible dimensions, given as (height, width).
+    DimensionMismatch {
+        left: (usize, usize),
+        right: (usize, usize),
+    },
+    /// Provided array is empty, so the dimensions can't be inferred.
+    Empty,
+    /// Provided (row, column) index is outside a matrix with the given (height, width).
+    IndexOutOfBounds {
+        index: (usize, usize),
+        dimensions: (usize, usize),
+    },
 }
 
 impl Display for MatrixError {
@@ -21,6 +39,24 @@ impl Display for MatrixError {
             Self::NotSquare => "provided matrix isn't square",
             Self::Singular => "provided matrix is singular",
             Self::UnequalRows => "provided array has unequal rows",
+            Self::NotSymmetric => "provided matrix isn't symmetric",
+            Self::NotPositiveDefinite => "provided matrix isn't positive definite",
+            Self::NoConvergence => "iterative algorithm didn't converge",
+            Self::Empty => "provided array is empty",
+            Self::DimensionMismatch { left, right } => {
+                return write!(
+                    f,
+                    "provided matrices have incompatible dimensions {}x{} and {}x{}",
+                    left.0, left.1, right.0, right.1
+                )
+            }
+            Self::IndexOutOfBounds { index, dimensions } => {
+                return write!(
+                    f,
+                    "index ({}, {}) is out of bounds for a {}x{} matrix",
+                    index.0, index.1, dimensions.0, dimensions.1
+                )
+            }
         };
         write!(f, "{out}")
     }
@@ -0,0 +1,144 @@
+use crate::{decompositions::householder_reduce, errors::MatrixError, Matrix};
+
+impl Matrix<f64> {
+    /// Returns the least squares solution of `AX = B`, i.e. the `X` minimizing the norm of
+    /// `AX - B`, where `A` is the provided matrix and `B` is given. All columns of `B` are solved
+    /// for at once. It uses the [`qr`](Self::qr()) decomposition, which is accurate even for
+    /// ill-conditioned matrices, without forming `Q`, so tall matrices only need memory
+    /// proportional to their size. See [`least_squares_normal`](Self::least_squares_normal())
+    /// for a faster alternative.
+    /// It'll throw an error if the heights of `A` and `B` don't match, or if the columns of `A`
+    /// are (numerically) linearly dependent, in which case [`pseudo_inverse`](Self::pseudo_inverse())
+    /// can be used instead.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// // Fitting a line y = c + dt through (0, 1), (1, 2) and (2, 4).
+    /// let a = Matrix::from(vec![vec![1.0, 0.0], vec![1.0, 1.0], vec![1.0, 2.0]]).unwrap();
+    /// let b = Matrix::from(vec![vec![1.0], vec![2.0], vec![4.0]]).unwrap();
+    /// let x = a.least_squares(&b).unwrap();
+    /// assert!((x.rows()[0][0] - 5.0 / 6.0).abs() < 1e-12);
+    /// assert!((x.rows()[1][0] - 1.5).abs() < 1e-12);
+    /// ```
+    pub fn least_squares(&self, rhs: &Self) -> Result<Self, MatrixError> {
+        let h = self.height();
+        let w = self.width();
+        if rhs.height() != h {
+            return Err(MatrixError::DimensionMismatch {
+                left: (h, w),
+                right: (rhs.height(), rhs.width()),
+            });
+        }
+        if h < w {
+            return Err(MatrixError::Singular);
+        }
+        // The reflections of the QR decomposition are applied to B directly, so that the
+        // h×h matrix Q is never formed, and B is replaced by QᵀB.
+        let mut augmented = Self::hstack(&[self, rhs])?;
+        householder_reduce(&mut augmented, w, false, None);
+        let stride = augmented.width;
+        let diagonal: Vec<f64> = (0..w).map(|i| augmented.entries[i * stride + i]).collect();
+        let largest = diagonal.iter().fold(0.0, |acc: f64, x| acc.max(x.abs()));
+        let tolerance = largest * f64::EPSILON * h as f64;
+        if diagonal.iter().any(|x| x.abs() <= tolerance) {
+            return Err(MatrixError::Singular);
+        }
+
+        // Since Q is orthogonal, we only need to solve RX = QᵀB, ignoring the zero rows of R.
+        let k = rhs.width;
+        let mut out = Self::zero(w, k);
+        for (out_row, row) in out.row_slices_mut().zip(augmented.row_slices()) {
+            out_row.copy_from_slice(&row[w..]);
+        }
+        for (i, r_row) in augmented.row_slices().take(w).enumerate().rev() {
+            let (above, below) = out.entries.split_at_mut((i + 1) * k);
+            let row = &mut above[i * k..];
+            for (other, ratio) in below.chunks_exact(k.max(1)).zip(&r_row[i + 1..w]) {
+                for (entry, other_entry) in row.iter_mut().zip(other) {
+                    *entry -= other_entry * ratio;
+                }
+            }
+            for entry in row.iter_mut() {
+                *entry /= r_row[i];
+            }
+        }
+        Ok(out)
+    }
+
+    /// Returns the least squares solution of `AX = B` by solving the normal equations
+    /// `AᵀAX = AᵀB` with the [`cholesky`](Self::cholesky()) decomposition. It's faster than
+    /// [`least_squares`](Self::least_squares()) for tall matrices, but squares the condition
+    /// number, so it's less accurate for ill-conditioned ones.
+    /// It'll throw an error if the heights of `A` and `B` don't match, or if the columns of `A`
+    /// are (numerically) linearly dependent.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let a = Matrix::from(vec![vec![1.0, 0.0], vec![1.0, 1.0], vec![1.0, 2.0]]).unwrap();
+    /// let b = Matrix::from(vec![vec![1.0], vec![2.0], vec![4.0]]).unwrap();
+    /// let x = a.least_squares_normal(&b).unwrap();
+    /// assert!((x.rows()[0][0] - 5.0 / 6.0).abs() < 1e-12);
+    /// assert!((x.rows()[1][0] - 1.5).abs() < 1e-12);
+    /// ```
+    pub fn least_squares_normal(&self, rhs: &Self) -> Result<Self, MatrixError> {
+        if rhs.height() != self.height() {
+            return Err(MatrixError::DimensionMismatch {
+                left: (self.height(), self.width()),
+                right: (rhs.height(), rhs.width()),
+            });
+        }
+        let transpose = self.transpose();
+        let normal = &transpose * self;
+        match normal.cholesky() {
+            Ok(cholesky) => cholesky.solve(&(transpose * rhs)),
+            Err(MatrixError::NotPositiveDefinite) => Err(MatrixError::Singular),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Returns the Moore–Penrose pseudo-inverse of a real matrix of any shape using the
+    /// [`svd`](Self::svd()). Singular values smaller than `max(m, n) * ε` times the largest one
+    /// are treated as zero, see [`pseudo_inverse_with_cutoff`](Self::pseudo_inverse_with_cutoff())
+    /// to change this. For invertible matrices, it's the same as the [`inverse`](Self::inverse()).
+    /// It'll throw an error if the SVD doesn't converge.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![1.0, 1.0], vec![1.0, 1.0]]).unwrap();
+    /// let n = Matrix::from(vec![vec![0.25, 0.25], vec![0.25, 0.25]]).unwrap();
+    /// let p = m.pseudo_inverse().unwrap();
+    /// assert!(p.rows().iter().flatten().zip(n.rows().iter().flatten()).all(|(x, y)| (x - y).abs() < 1e-12));
+    /// ```
+    pub fn pseudo_inverse(&self) -> Result<Self, MatrixError> {
+        let cutoff = f64::EPSILON * self.height().max(self.width()) as f64;
+        self.pseudo_inverse_with_cutoff(cutoff)
+    }
+
+    /// Returns the Moore–Penrose pseudo-inverse of a real matrix, treating singular values not
+    /// larger than `cutoff` times the largest one as zero. Larger cutoffs make the result less
+    /// sensitive to noise, see [`pseudo_inverse`](Self::pseudo_inverse()) for the default.
+    /// It'll throw an error if the SVD doesn't converge.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![2.0, 0.0], vec![0.0, 1e-8]]).unwrap();
+    /// let n = Matrix::from(vec![vec![0.5, 0.0], vec![0.0, 0.0]]).unwrap();
+    /// assert_eq!(m.pseudo_inverse_with_cutoff(1e-6), Ok(n));
+    /// ```
+    pub fn pseudo_inverse_with_cutoff(&self, cutoff: f64) -> Result<Self, MatrixError> {
+        let svd = self.svd()?;
+        let largest = svd.singular_values.first().copied().unwrap_or(0.0);
+        // A⁺ = VΣ⁺Uᵀ, where Σ⁺ inverts the non-negligible singular values.
+        let mut vs = svd.vt.transpose();
+        for row in vs.row_slices_mut() {
+            for (entry, value) in row.iter_mut().zip(&svd.singular_values) {
+                *entry = if *value > cutoff * largest {
+                    *entry / value
+                } else {
+                    0.0
+                };
+            }
+        }
+        Ok(vs * svd.u.transpose())
+    }
+}
@@ -3,6 +3,8 @@
 //! [`Zero`], [`Neg`] and [`Copy`]. Additional properties might be
 //! needed for certain operations.
 //!
+//! Random matrix generators are available with the `rand` feature.
+//!
 //! I created it mostly to learn using generic types
 //! and traits.
 //!
@@ -10,17 +12,30 @@
 
 use errors::MatrixError;
 use num::{
+    rational::Ratio,
     traits::{One, Zero},
-    Integer,
+    Complex, Integer,
 };
 use std::{
     fmt::{self, Debug, Display, Formatter},
-    ops::{Add, Div, Mul, Neg, Sub},
+    ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign},
     result::Result,
 };
 
+mod blocks;
+pub mod decompositions;
+mod eigen;
 pub mod errors;
+mod least_squares;
+mod linear_systems;
+pub use linear_systems::SolutionSet;
+mod polynomials;
+#[cfg(feature = "rand")]
+mod random;
+mod subspaces;
 mod tests;
+mod views;
+pub use views::{MatrixView, MatrixViewMut};
 
 /// Trait a type must satisfy to be element of a matrix. This is
 /// mostly to reduce writing trait bounds afterwards.
@@ -45,17 +60,150 @@ impl<T> ToMatrix for T where
 {
 }
 
+/// Trait deciding how pivots are chosen when doing row reduction, e.g. in
+/// [`lu`](Matrix::lu()), [`det_in_field`](Matrix::det_in_field()) or [`inverse`](Matrix::inverse()).
+/// By default, the first non-zero entry is chosen, which is the right thing for exact types
+/// like rationals or finite fields. For those, an empty implementation is enough.
+/// # Example
+/// ```
+/// use matrix_basic::Pivot;
+/// # use num::Zero;
+/// # use std::ops::Add;
+/// #[derive(Clone, Copy, PartialEq)]
+/// struct Mod7(u8);
+/// # impl Add for Mod7 {
+/// #     type Output = Self;
+/// #     fn add(self, other: Self) -> Self {
+/// #         Mod7((self.0 + other.0) % 7)
+/// #     }
+/// # }
+/// # impl Zero for Mod7 {
+/// #     fn zero() -> Self {
+/// #         Mod7(0)
+/// #     }
+/// #     fn is_zero(&self) -> bool {
+/// #         self.0 == 0
+/// #     }
+/// # }
+///
+/// impl Pivot for Mod7 {}
+/// ```
+/// Floating point types instead choose the entry with the largest absolute value
+/// (partial pivoting), which is needed for numerical stability. Where the rank matters, e.g. in
+/// [`rank`](Matrix::rank()), [`nullspace`](Matrix::nullspace()) or [`solve`](Matrix::solve()),
+/// they also treat entries below `n·ε·max|A|` as zero, where `n` is the larger dimension of the
+/// matrix `A` and `ε` is the machine epsilon, so that rounding errors don't make singular
+/// matrices look like they have full rank. Other operations like [`lu`](Matrix::lu()) or
+/// [`inverse`](Matrix::inverse()) only treat exact zeros as zero.
+pub trait Pivot: Zero + PartialEq + Sized {
+    /// Returns the index of the entry to be used as a pivot among `candidates`,
+    /// or [`None`] if all of them are zero.
+    fn pivot(candidates: &[Self]) -> Option<usize> {
+        Self::first_non_zero(candidates)
+    }
+
+    /// Returns the index of the first non-zero entry among `candidates`. This is the
+    /// exact-zero strategy, see [`lu_with`](Matrix::lu_with()) for using it with any type.
+    fn first_non_zero(candidates: &[Self]) -> Option<usize> {
+        candidates.iter().position(|entry| *entry != Self::zero())
+    }
+
+    /// Returns the tolerance for a matrix with the given entries, whose larger dimension is
+    /// `size`, see [`is_negligible`](Self::is_negligible()). It's zero by default.
+    fn tolerance(_entries: &[Self], _size: usize) -> Self {
+        Self::zero()
+    }
+
+    /// Returns whether an entry is negligible compared to the given tolerance, in which case
+    /// it's treated as zero during row reduction. By default, only zero is negligible.
+    fn is_negligible(&self, _tolerance: &Self) -> bool {
+        *self == Self::zero()
+    }
+}
+
+/// Returns the index of the entry with the largest magnitude, or [`None`] if it's zero.
+/// An entry whose magnitude is NaN is chosen right away, so that it propagates to the result
+/// instead of being mistaken for zero.
+fn largest_magnitude<T, M: PartialOrd + Zero>(
+    candidates: &[T],
+    magnitude: impl Fn(&T) -> M,
+) -> Option<usize> {
+    let mut out = None;
+    let mut largest = M::zero();
+    for (i, entry) in candidates.iter().enumerate() {
+        let current = magnitude(entry);
+        if current.partial_cmp(&largest).is_none() {
+            return Some(i);
+        }
+        if current > largest {
+            out = Some(i);
+            largest = current;
+        }
+    }
+    out
+}
+
+macro_rules! impl_exact_pivot {
+    ($($t:ty)*) => ($(
+        impl Pivot for $t {}
+    )*)
+}
+
+impl_exact_pivot!(i8 i16 i32 i64 i128 isize);
+
+impl<T: Clone + Integer> Pivot for Ratio<T> {}
+
+macro_rules! impl_partial_pivot {
+    ($($t:ty)*) => ($(
+        impl Pivot for $t {
+            fn pivot(candidates: &[Self]) -> Option<usize> {
+                largest_magnitude(candidates, |entry| entry.abs())
+            }
+
+            fn tolerance(entries: &[Self], size: usize) -> Self {
+                let largest = entries.iter().fold(0.0, |acc: $t, entry| acc.max(entry.abs()));
+                size as $t * <$t>::EPSILON * largest
+            }
+
+            fn is_negligible(&self, tolerance: &Self) -> bool {
+                self.abs() <= *tolerance
+            }
+        }
+
+        impl Pivot for Complex<$t> {
+            fn pivot(candidates: &[Self]) -> Option<usize> {
+                largest_magnitude(candidates, |entry| entry.norm_sqr())
+            }
+
+            fn tolerance(entries: &[Self], size: usize) -> Self {
+                let largest = entries.iter().fold(0.0, |acc: $t, entry| acc.max(entry.norm()));
+                Complex::new(size as $t * <$t>::EPSILON * largest, 0.0)
+            }
+
+            fn is_negligible(&self, tolerance: &Self) -> bool {
+                self.norm() <= tolerance.re
+            }
+        }
+    )*)
+}
+
+impl_partial_pivot!(f32 f64);
+
 /// A generic matrix struct (over any type with [`Add`], [`Sub`], [`Mul`],
 /// [`Zero`], [`Neg`] and [`Copy`] implemented).
+/// The entries are stored contiguously in row-major order.
 /// Look at [`from`](Self::from()) to see examples.
 #[derive(PartialEq, Debug, Clone)]
 pub struct Matrix<T: ToMatrix> {
-    entries: Vec<Vec<T>>,
+    entries: Vec<T>,
+    height: usize,
+    width: usize,
 }
 
 impl<T: ToMatrix> Matrix<T> {
     /// Creates a matrix from given 2D "array" in a [`Vec<Vec<T>>`] form.
-    /// It'll throw an error if all the given rows aren't of the same size.
+    /// It'll throw an error if all the given rows aren't of the same size, or if there are no
+    /// rows, as then the width can't be inferred. Use [`from_shape`](Self::from_shape()) for that.
     /// # Example
     /// ```
     /// use matrix_basic::Matrix;
@@ -65,8 +213,11 @@ impl<T: ToMatrix> Matrix<T> {
     /// ⌈1, 2, 3⌉  
     /// ⌊4, 5, 6⌋
     pub fn from(entries: Vec<Vec<T>>) -> Result<Matrix<T>, MatrixError> {
+        let Some(first) = entries.first() else {
+            return Err(MatrixError::Empty);
+        };
         let mut equal_rows = true;
-        let row_len = entries[0].len();
+        let row_len = first.len();
         for row in &entries {
             if row_len != row.len() {
                 equal_rows = false;
@@ -74,43 +225,210 @@ impl<T: ToMatrix> Matrix<T> {
             }
         }
         if equal_rows {
-            Ok(Matrix { entries })
+            Ok(Self::from_rows(entries, row_len))
         } else {
             Err(MatrixError::UnequalRows)
         }
     }
 
+    /// Creates a matrix of the given dimensions from a 2D "array" in a [`Vec<Vec<T>>`] form.
+    /// Unlike [`from`](Self::from()), this also works for matrices without rows.
+    /// It'll throw an error if the given rows don't match the dimensions.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::<i32>::from_shape(0, 3, vec![]).unwrap();
+    /// assert_eq!((m.height(), m.width()), (0, 3));
+    /// assert_eq!(m.transpose(), Matrix::from(vec![vec![], vec![], vec![]]).unwrap());
+    /// ```
+    pub fn from_shape(
+        height: usize,
+        width: usize,
+        entries: Vec<Vec<T>>,
+    ) -> Result<Matrix<T>, MatrixError> {
+        if entries.iter().any(|row| row.len() != width) {
+            return Err(MatrixError::UnequalRows);
+        }
+        if entries.len() != height {
+            return Err(MatrixError::DimensionMismatch {
+                left: (height, width),
+                right: (entries.len(), width),
+            });
+        }
+        Ok(Matrix {
+            entries: entries.into_iter().flatten().collect(),
+            height,
+            width,
+        })
+    }
+
+    /// Creates a matrix of the given dimensions whose entry in the `i`-th row and `j`-th
+    /// column (0-indexed) is `f(i, j)`.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from_fn(2, 3, |i, j| (i * 3 + j) as i32);
+    /// assert_eq!(m, Matrix::from(vec![vec![0, 1, 2], vec![3, 4, 5]]).unwrap());
+    /// ```
+    pub fn from_fn(height: usize, width: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let mut entries = Vec::with_capacity(height * width);
+        for i in 0..height {
+            for j in 0..width {
+                entries.push(f(i, j));
+            }
+        }
+        Matrix {
+            entries,
+            height,
+            width,
+        }
+    }
+
+    /// Creates a matrix of the given dimensions from its entries in row-major order,
+    /// i.e. the first row comes first. It doesn't copy the entries.
+    /// It'll throw an error if the number of entries isn't `height * width`.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from_flat(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// assert_eq!(m, Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap());
+    /// ```
+    pub fn from_flat(height: usize, width: usize, entries: Vec<T>) -> Result<Self, MatrixError> {
+        if entries.len() != height * width {
+            return Err(MatrixError::DimensionMismatch {
+                left: (height, width),
+                right: (1, entries.len()),
+            });
+        }
+        Ok(Matrix {
+            entries,
+            height,
+            width,
+        })
+    }
+
+    /// Creates a matrix of the given dimensions from its entries in column-major order,
+    /// i.e. the first column comes first.
+    /// It'll throw an error if the number of entries isn't `height * width`.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from_flat_column_major(2, 3, vec![1, 4, 2, 5, 3, 6]).unwrap();
+    /// assert_eq!(m, Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap());
+    /// ```
+    pub fn from_flat_column_major(
+        height: usize,
+        width: usize,
+        entries: Vec<T>,
+    ) -> Result<Self, MatrixError> {
+        if entries.len() != height * width {
+            return Err(MatrixError::DimensionMismatch {
+                left: (height, width),
+                right: (1, entries.len()),
+            });
+        }
+        let transpose = Matrix {
+            entries,
+            height: width,
+            width: height,
+        };
+        Ok(transpose.transpose())
+    }
+
+    /// Creates a matrix from rows which are known to be of the given width.
+    pub(crate) fn from_rows(rows: Vec<Vec<T>>, width: usize) -> Self {
+        let height = rows.len();
+        Matrix {
+            entries: rows.into_iter().flatten().collect(),
+            height,
+            width,
+        }
+    }
+
     /// Returns the height of a matrix.
     pub fn height(&self) -> usize {
-        self.entries.len()
+        self.height
     }
 
     /// Returns the width of a matrix.
     pub fn width(&self) -> usize {
-        self.entries[0].len()
+        self.width
     }
 
     /// Returns the transpose of a matrix.
     pub fn transpose(&self) -> Self {
-        let mut out = Vec::new();
-        for i in 0..self.width() {
-            let mut column = Vec::new();
-            for row in &self.entries {
-                column.push(row[i]);
-            }
-            out.push(column)
+        let mut entries = Vec::with_capacity(self.entries.len());
+        for j in 0..self.width {
+            entries.extend(self.entries.iter().skip(j).step_by(self.width));
+        }
+        Matrix {
+            entries,
+            height: self.width,
+            width: self.height,
         }
-        Matrix { entries: out }
     }
 
-    /// Returns a reference to the rows of a matrix as `&Vec<Vec<T>>`.
-    pub fn rows(&self) -> &Vec<Vec<T>> {
-        &self.entries
+    /// Returns the rows of a matrix as `Vec<Vec<T>>`.
+    /// This copies the entries, see [`as_slice`](Self::as_slice()) for borrowing them.
+    pub fn rows(&self) -> Vec<Vec<T>> {
+        self.row_slices().map(<[T]>::to_vec).collect()
     }
 
     /// Return the columns of a matrix as `Vec<Vec<T>>`.
+    /// See [`column`](Self::column()) for borrowing a single column.
     pub fn columns(&self) -> Vec<Vec<T>> {
-        self.transpose().entries
+        (0..self.width)
+            .map(|j| self.column(j).iter().copied().collect())
+            .collect()
+    }
+
+    /// Returns the entries of a matrix in row-major order.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// assert_eq!(m.as_slice(), &[1, 2, 3, 4]);
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        &self.entries
+    }
+
+    /// Returns an iterator over the rows of a matrix as slices.
+    pub(crate) fn row_slices(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        (0..self.height).map(move |i| &self.entries[i * self.width..(i + 1) * self.width])
+    }
+
+    /// Returns an iterator over the rows of a matrix as mutable slices.
+    pub(crate) fn row_slices_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        // The length of the chunks has to be non-zero, but there's nothing to mutate anyway.
+        self.entries.chunks_exact_mut(self.width.max(1))
+    }
+
+    /// Returns the `i`-th row of a matrix as a slice.
+    pub(crate) fn row_slice(&self, i: usize) -> &[T] {
+        &self.entries[i * self.width..(i + 1) * self.width]
+    }
+
+    /// Returns the `i`-th row of a matrix as a mutable slice.
+    pub(crate) fn row_slice_mut(&mut self, i: usize) -> &mut [T] {
+        &mut self.entries[i * self.width..(i + 1) * self.width]
+    }
+
+    /// Returns the `i`-th and `j`-th rows of a matrix as mutable slices, where `i < j`.
+    pub(crate) fn row_pair_mut(&mut self, i: usize, j: usize) -> (&mut [T], &mut [T]) {
+        let (above, below) = self.entries.split_at_mut(j * self.width);
+        (
+            &mut above[i * self.width..(i + 1) * self.width],
+            &mut below[..self.width],
+        )
+    }
+
+    /// Swaps two rows of a matrix in place.
+    pub(crate) fn swap_rows(&mut self, i: usize, j: usize) {
+        if i != j {
+            let (upper, lower) = self.row_pair_mut(i.min(j), i.max(j));
+            upper.swap_with_slice(lower);
+        }
     }
 
     /// Return true if a matrix is square and false otherwise.
@@ -118,8 +436,87 @@ impl<T: ToMatrix> Matrix<T> {
         self.height() == self.width()
     }
 
+    /// Return true if a matrix is symmetric and false otherwise.
+    /// It needs the [`PartialEq`] trait.
+    pub fn is_symmetric(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        self.is_square() && *self == self.transpose()
+    }
+
+    /// Returns the position of the entry at the given (0-indexed) row and column
+    /// in the underlying storage, or an error if it's out of bounds.
+    fn offset(&self, i: usize, j: usize) -> Result<usize, MatrixError> {
+        if i < self.height && j < self.width {
+            Ok(i * self.width + j)
+        } else {
+            Err(MatrixError::IndexOutOfBounds {
+                index: (i, j),
+                dimensions: (self.height, self.width),
+            })
+        }
+    }
+
+    /// Returns a reference to the entry at the given (0-indexed) row and column,
+    /// or [`None`] if it's out of bounds. See [`Index`] for the panicking version.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// assert_eq!(m.get(1, 2), Some(&6));
+    /// assert_eq!(m[(0, 1)], 2);
+    /// assert_eq!(m.get(2, 0), None);
+    /// ```
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        self.offset(i, j).ok().map(|k| &self.entries[k])
+    }
+
+    /// Returns a mutable reference to the entry at the given (0-indexed) row and column,
+    /// or [`None`] if it's out of bounds. See [`IndexMut`] for the panicking version.
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        self.offset(i, j).ok().map(|k| &mut self.entries[k])
+    }
+
+    /// Sets the entry at the given (0-indexed) row and column to a value.
+    /// It'll throw an error if the position is out of bounds.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let mut m = Matrix::from(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// m.set(0, 1, 5).unwrap();
+    /// assert_eq!(m, Matrix::from(vec![vec![1, 5], vec![3, 4]]).unwrap());
+    /// assert!(m.set(0, 2, 5).is_err());
+    /// ```
+    pub fn set(&mut self, i: usize, j: usize, value: T) -> Result<(), MatrixError> {
+        let k = self.offset(i, j)?;
+        self.entries[k] = value;
+        Ok(())
+    }
+
+    /// Swaps the entries at the given (0-indexed) positions.
+    /// It'll throw an error if any of the positions is out of bounds.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let mut m = Matrix::from(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// m.swap_entries((0, 0), (1, 1)).unwrap();
+    /// assert_eq!(m, Matrix::from(vec![vec![4, 2], vec![3, 1]]).unwrap());
+    /// ```
+    pub fn swap_entries(
+        &mut self,
+        first: (usize, usize),
+        second: (usize, usize),
+    ) -> Result<(), MatrixError> {
+        let k = self.offset(first.0, first.1)?;
+        let l = self.offset(second.0, second.1)?;
+        self.entries.swap(k, l);
+        Ok(())
+    }
+
     /// Returns a matrix after removing the provided row and column from it.
     /// Note: Row and column numbers are 0-indexed.
+    /// It'll panic if the row or the column is out of bounds.
     /// # Example
     /// ```
     /// use matrix_basic::Matrix;
@@ -128,25 +525,35 @@ impl<T: ToMatrix> Matrix<T> {
     /// assert_eq!(m.submatrix(0, 0), n);
     /// ```
     pub fn submatrix(&self, row: usize, col: usize) -> Self {
-        let mut out = Vec::new();
-        for (m, row_iter) in self.entries.iter().enumerate() {
+        if let Err(error) = self.offset(row, col) {
+            panic!("{error}");
+        }
+        let mut entries = Vec::with_capacity((self.height - 1) * (self.width - 1));
+        for (m, row_iter) in self.row_slices().enumerate() {
             if m == row {
                 continue;
             }
-            let mut new_row = Vec::new();
             for (n, entry) in row_iter.iter().enumerate() {
                 if n != col {
-                    new_row.push(*entry);
+                    entries.push(*entry);
                 }
             }
-            out.push(new_row);
         }
-        Matrix { entries: out }
+        Matrix {
+            entries,
+            height: self.height - 1,
+            width: self.width - 1,
+        }
     }
 
     /// Returns the determinant of a square matrix.
     /// This uses basic recursive algorithm using cofactor-minor.
-    /// See [`det_in_field`](Self::det_in_field()) for faster determinant calculation in fields.
+    /// See [`det_in_field`](Self::det_in_field()) for faster determinant calculation in fields,
+    /// [`det_bareiss`](Self::det_bareiss()) for integers, and [`berkowitz`](Self::berkowitz())
+    /// for arbitrary commutative rings.
+    /// Picking the faster algorithm automatically would need specialization, which isn't
+    /// available in stable Rust yet. You can track this issue [here](https://github.com/rust-lang/rust/issues/31844).
+    /// The determinant of a 0×0 matrix is 1, as it's an empty product.
     /// It'll throw an error if the provided matrix isn't square.
     /// # Example
     /// ```
@@ -154,21 +561,26 @@ impl<T: ToMatrix> Matrix<T> {
     /// let m = Matrix::from(vec![vec![1, 2], vec![3, 4]]).unwrap();
     /// assert_eq!(m.det(), Ok(-2));
     /// ```
-    pub fn det(&self) -> Result<T, MatrixError> {
+    pub fn det(&self) -> Result<T, MatrixError>
+    where
+        T: One,
+    {
         if self.is_square() {
             // It's a recursive algorithm using minors.
             // TODO: Implement a faster algorithm.
-            let out = if self.width() == 1 {
-                self.entries[0][0]
+            let out = if self.width() == 0 {
+                T::one()
+            } else if self.width() == 1 {
+                self.entries[0]
             } else {
                 // Add the minors multiplied by cofactors.
                 let n = 0..self.width();
                 let mut out = T::zero();
                 for i in n {
                     if i.is_even() {
-                        out = out + (self.entries[0][i] * self.submatrix(0, i).det().unwrap());
+                        out = out + (self.entries[i] * self.submatrix(0, i).det().unwrap());
                     } else {
-                        out = out - (self.entries[0][i] * self.submatrix(0, i).det().unwrap());
+                        out = out - (self.entries[i] * self.submatrix(0, i).det().unwrap());
                     }
                 }
                 out
@@ -179,17 +591,19 @@ impl<T: ToMatrix> Matrix<T> {
         }
     }
 
-    /// Returns the determinant of a square matrix over a field i.e. needs [`One`] and [`Div`] traits.
-    /// See [`det`](Self::det()) for determinants in rings.
-    /// This method uses row reduction as is much faster.
+    /// Returns the determinant of a square matrix over an integral domain with exact division
+    /// e.g. integers, i.e. needs [`One`] and [`Div`] traits. This uses the fraction-free Bareiss
+    /// algorithm, so every division it does is exact and all intermediate values stay in the ring.
+    /// It's much faster than [`det`](Self::det()) and, unlike [`det_in_field`](Self::det_in_field()),
+    /// gives correct results with truncating division.
     /// It'll throw an error if the provided matrix isn't square.
     /// # Example
     /// ```
     /// use matrix_basic::Matrix;
-    /// let m = Matrix::from(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
-    /// assert_eq!(m.det_in_field(), Ok(-2.0));
+    /// let m = Matrix::from(vec![vec![2, 3, 1], vec![4, 1, 5], vec![3, 2, 2]]).unwrap();
+    /// assert_eq!(m.det_bareiss(), Ok(10));
     /// ```
-    pub fn det_in_field(&self) -> Result<T, MatrixError>
+    pub fn det_bareiss(&self) -> Result<T, MatrixError>
     where
         T: One,
         T: PartialEq,
@@ -197,139 +611,112 @@ impl<T: ToMatrix> Matrix<T> {
     {
         if self.is_square() {
             // Cloning is necessary as we'll be doing row operations on it.
-            let mut rows = self.entries.clone();
+            let mut rows = self.clone();
             let mut multiplier = T::one();
+            let mut previous = T::one();
             let h = self.height();
-            let w = self.width();
-            for i in 0..(h - 1) {
+            for i in 0..h {
                 // First check if the row has diagonal element 0, if yes, then swap.
-                if rows[i][i] == T::zero() {
-                    let mut zero_column = true;
-                    for j in (i + 1)..h {
-                        if rows[j][i] != T::zero() {
-                            rows.swap(i, j);
+                if rows.entries[i * h + i] == T::zero() {
+                    match (i + 1..h).find(|&j| rows.entries[j * h + i] != T::zero()) {
+                        Some(j) => {
+                            rows.swap_rows(i, j);
                             multiplier = -multiplier;
-                            zero_column = false;
-                            break;
                         }
-                    }
-                    if zero_column {
-                        return Ok(T::zero());
+                        None => return Ok(T::zero()),
                     }
                 }
-                for j in (i + 1)..h {
-                    let ratio = rows[j][i] / rows[i][i];
-                    for k in i..w {
-                        rows[j][k] = rows[j][k] - rows[i][k] * ratio;
+                let pivot_row = rows.row_slice(i).to_vec();
+                for row in rows.row_slices_mut().skip(i + 1) {
+                    // This division is always exact by Sylvester's identity.
+                    for k in (i + 1)..h {
+                        row[k] = (row[k] * pivot_row[i] - row[i] * pivot_row[k]) / previous;
                     }
                 }
+                previous = pivot_row[i];
             }
-            for (i, row) in rows.iter().enumerate() {
-                multiplier = multiplier * row[i];
-            }
-            Ok(multiplier)
+            Ok(multiplier * previous)
         } else {
             Err(MatrixError::NotSquare)
         }
     }
 
-    /// Returns the row echelon form of a matrix over a field i.e. needs the [`Div`] trait.
+    /// Returns the determinant of a square matrix over a field i.e. needs [`One`] and [`Div`] traits.
+    /// See [`det`](Self::det()) for determinants in rings.
+    /// This method uses row reduction (through [`lu`](Self::lu())) and is much faster.
+    /// It'll throw an error if the provided matrix isn't square.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+    /// assert_eq!(m.det_in_field(), Ok(-2.0));
+    /// ```
+    pub fn det_in_field(&self) -> Result<T, MatrixError>
+    where
+        T: One,
+        T: Pivot,
+        T: Div<Output = T>,
+    {
+        self.lu().det()
+    }
+
+    /// Returns the row echelon form of a matrix over a field i.e. needs [`One`] and [`Div`] traits.
+    /// This is the `U` part of the [`lu`](Self::lu()) decomposition, so the pivots are chosen
+    /// according to [`Pivot`]. Use [`lu_with`](Self::lu_with()) for a different strategy.
     /// # Example
     /// ```
     /// use matrix_basic::Matrix;
     /// let m = Matrix::from(vec![vec![1.0, 2.0, 3.0], vec![3.0, 4.0, 5.0]]).unwrap();
-    /// let n = Matrix::from(vec![vec![1.0, 2.0, 3.0], vec![0.0, -2.0, -4.0]]).unwrap();
-    /// assert_eq!(m.row_echelon(), n);
+    /// let n: Matrix<f64> = Matrix::from(vec![vec![3.0, 4.0, 5.0], vec![0.0, 2.0 / 3.0, 4.0 / 3.0]]).unwrap();
+    /// let u = m.row_echelon();
+    /// assert!(u.as_slice().iter().zip(n.as_slice()).all(|(x, y)| (x - y).abs() < 1e-12));
     /// ```
     pub fn row_echelon(&self) -> Self
     where
-        T: PartialEq,
+        T: One,
+        T: Pivot,
         T: Div<Output = T>,
     {
-        // Cloning is necessary as we'll be doing row operations on it.
-        let mut rows = self.entries.clone();
-        let mut offset = 0;
-        let h = self.height();
-        let w = self.width();
-        for i in 0..(h - 1) {
-            // Check if all the rows below are 0
-            if i + offset >= self.width() {
-                break;
-            }
-            // First check if the row has diagonal element 0, if yes, then swap.
-            if rows[i][i + offset] == T::zero() {
-                let mut zero_column = true;
-                for j in (i + 1)..h {
-                    if rows[j][i + offset] != T::zero() {
-                        rows.swap(i, j);
-                        zero_column = false;
-                        break;
-                    }
-                }
-                if zero_column {
-                    offset += 1;
-                }
-            }
-            for j in (i + 1)..h {
-                let ratio = rows[j][i + offset] / rows[i][i + offset];
-                for k in (i + offset)..w {
-                    rows[j][k] = rows[j][k] - rows[i][k] * ratio;
-                }
-            }
-        }
-        Matrix { entries: rows }
+        self.lu().u
     }
 
-    /// Returns the column echelon form of a matrix over a field i.e. needs the [`Div`] trait.
+    /// Returns the column echelon form of a matrix over a field i.e. needs [`One`] and [`Div`] traits.
     /// It's just the transpose of the row echelon form of the transpose.
     /// See [`row_echelon`](Self::row_echelon()) and [`transpose`](Self::transpose()).
     pub fn column_echelon(&self) -> Self
     where
-        T: PartialEq,
+        T: One,
+        T: Pivot,
         T: Div<Output = T>,
     {
         self.transpose().row_echelon().transpose()
     }
 
-    /// Returns the reduced row echelon form of a matrix over a field i.e. needs the `Div`] trait.
+    /// Returns the reduced row echelon form of a matrix over a field i.e. needs [`One`] and [`Div`] traits.
+    /// See [`reduced_echelon`](Self::reduced_echelon()) to get the pivot columns and the rank too.
     /// # Example
     /// ```
     /// use matrix_basic::Matrix;
     /// let m = Matrix::from(vec![vec![1.0, 2.0, 3.0], vec![3.0, 4.0, 5.0]]).unwrap();
-    /// let n = Matrix::from(vec![vec![1.0, 2.0, 3.0], vec![0.0, 1.0, 2.0]]).unwrap();
+    /// let n = Matrix::from(vec![vec![1.0, 0.0, -1.0], vec![0.0, 1.0, 2.0]]).unwrap();
     /// assert_eq!(m.reduced_row_echelon(), n);
     /// ```
     pub fn reduced_row_echelon(&self) -> Self
     where
-        T: PartialEq,
+        T: One,
+        T: Pivot,
         T: Div<Output = T>,
     {
-        let mut echelon = self.row_echelon();
-        let mut offset = 0;
-        for row in &mut echelon.entries {
-            while row[offset] == T::zero() {
-                offset += 1;
-            }
-            let divisor = row[offset];
-            for entry in row.iter_mut().skip(offset) {
-                *entry = *entry / divisor;
-            }
-            offset += 1;
-        }
-        echelon
+        self.reduced_echelon().matrix
     }
 
     /// Creates a zero matrix of a given size.
     pub fn zero(height: usize, width: usize) -> Self {
-        let mut out = Vec::new();
-        for _ in 0..height {
-            let mut new_row = Vec::new();
-            for _ in 0..width {
-                new_row.push(T::zero());
-            }
-            out.push(new_row);
+        Matrix {
+            entries: vec![T::zero(); height * width],
+            height,
+            width,
         }
-        Matrix { entries: out }
     }
 
     /// Creates an identity matrix of a given size.
@@ -339,8 +726,8 @@ impl<T: ToMatrix> Matrix<T> {
         T: One,
     {
         let mut out = Matrix::zero(size, size);
-        for (i, row) in out.entries.iter_mut().enumerate() {
-            row[i] = T::one();
+        for entry in out.entries.iter_mut().step_by(size + 1) {
+            *entry = T::one();
         }
         out
     }
@@ -355,11 +742,8 @@ impl<T: ToMatrix> Matrix<T> {
     /// ```
     pub fn trace(self) -> Result<T, MatrixError> {
         if self.is_square() {
-            let mut out = self.entries[0][0];
-            for i in 1..self.height() {
-                out = out + self.entries[i][i];
-            }
-            Ok(out)
+            let diagonal = self.entries.iter().step_by(self.width + 1);
+            Ok(diagonal.fold(T::zero(), |acc, entry| acc + *entry))
         } else {
             Err(MatrixError::NotSquare)
         }
@@ -377,14 +761,198 @@ impl<T: ToMatrix> Matrix<T> {
     pub fn diagonal_matrix(diag: Vec<T>) -> Self {
         let size = diag.len();
         let mut out = Matrix::zero(size, size);
-        for (i, row) in out.entries.iter_mut().enumerate() {
-            row[i] = diag[i];
+        for (entry, value) in out.entries.iter_mut().step_by(size + 1).zip(diag) {
+            *entry = value;
         }
         out
     }
 
+    /// Returns the Vandermonde matrix of the given points up to the given degree.
+    /// The `i`-th row is `1, xᵢ, xᵢ², ..., xᵢᵈ`, so multiplying it with the (ascending)
+    /// coefficients of a polynomial evaluates it at the points.
+    /// It needs the [`One`] trait.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::vandermonde(&[2, 3], 2);
+    /// assert_eq!(m, Matrix::from(vec![vec![1, 2, 4], vec![1, 3, 9]]).unwrap());
+    /// ```
+    pub fn vandermonde(xs: &[T], degree: usize) -> Self
+    where
+        T: One,
+    {
+        let mut out = Matrix::zero(xs.len(), degree + 1);
+        for (row, x) in out.row_slices_mut().zip(xs) {
+            let mut power = T::one();
+            for entry in row {
+                *entry = power;
+                power = power * *x;
+            }
+        }
+        out
+    }
+
+    /// Returns the `n×n` Hilbert matrix, whose entries are `1 / (i + j + 1)` (0-indexed).
+    /// It's a classic example of an ill-conditioned matrix.
+    /// It needs the [`One`] and [`Div`] traits, and should be used with exact types like
+    /// rationals or with floating point types, as integer division truncates.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::<f64>::hilbert(2);
+    /// assert_eq!(m, Matrix::from(vec![vec![1.0, 0.5], vec![0.5, 1.0 / 3.0]]).unwrap());
+    /// ```
+    pub fn hilbert(n: usize) -> Self
+    where
+        T: One,
+        T: Div<Output = T>,
+    {
+        // The denominators 1, 2, ..., 2n - 1 built by repeated addition.
+        let denominators: Vec<T> = (1..2 * n)
+            .scan(T::zero(), |k, _| {
+                *k = *k + T::one();
+                Some(*k)
+            })
+            .collect();
+        Self::from_fn(n, n, |i, j| T::one() / denominators[i + j])
+    }
+
+    /// Returns the Toeplitz matrix with the given first column and first row, i.e. each
+    /// descending diagonal is constant. The first entry of `row` is ignored in favour of `col`.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::toeplitz(&[1, 2, 3], &[0, 4]);
+    /// assert_eq!(m, Matrix::from(vec![vec![1, 4], vec![2, 1], vec![3, 2]]).unwrap());
+    /// ```
+    pub fn toeplitz(col: &[T], row: &[T]) -> Self {
+        Self::from_fn(col.len(), row.len(), |i, j| {
+            if i >= j {
+                col[i - j]
+            } else {
+                row[j - i]
+            }
+        })
+    }
+
+    /// Returns the Hankel matrix with the given first column and last row, i.e. each
+    /// ascending diagonal is constant. The first entry of `row` is ignored in favour of `col`.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::hankel(&[1, 2, 3], &[0, 4]);
+    /// assert_eq!(m, Matrix::from(vec![vec![1, 2], vec![2, 3], vec![3, 4]]).unwrap());
+    /// ```
+    pub fn hankel(col: &[T], row: &[T]) -> Self {
+        let h = col.len();
+        Self::from_fn(h, row.len(), |i, j| {
+            if i + j < h {
+                col[i + j]
+            } else {
+                row[i + j + 1 - h]
+            }
+        })
+    }
+
+    /// Returns the circulant matrix with the given first column, i.e. each column is
+    /// the previous one shifted down cyclically.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::circulant(&[1, 2, 3]);
+    /// assert_eq!(m, Matrix::from(vec![vec![1, 3, 2], vec![2, 1, 3], vec![3, 2, 1]]).unwrap());
+    /// ```
+    pub fn circulant(v: &[T]) -> Self {
+        let n = v.len();
+        Self::from_fn(n, n, |i, j| v[(n + i - j) % n])
+    }
+
+    /// Returns the companion matrix of a polynomial given by its coefficients in ascending
+    /// order, whose [characteristic polynomial](Self::characteristic_polynomial()) is the given
+    /// one divided by its leading coefficient. It has ones below the diagonal, and the negated
+    /// (normalized) coefficients in the last column.
+    /// It needs the [`One`] and [`Div`] traits.
+    /// It'll throw an error if all the coefficients are zero.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// // x² - 3x + 2
+    /// let m = Matrix::companion(&[2, -3, 1]).unwrap();
+    /// assert_eq!(m, Matrix::from(vec![vec![0, -2], vec![1, 3]]).unwrap());
+    /// ```
+    pub fn companion(poly_coeffs: &[T]) -> Result<Self, MatrixError>
+    where
+        T: One,
+        T: PartialEq,
+        T: Div<Output = T>,
+    {
+        // Trailing zeros don't change the polynomial.
+        let Some(degree) = poly_coeffs.iter().rposition(|coeff| *coeff != T::zero()) else {
+            return Err(MatrixError::Empty);
+        };
+        let leading = poly_coeffs[degree];
+        let mut out = Matrix::zero(degree, degree);
+        for (i, row) in out.row_slices_mut().enumerate() {
+            if i > 0 {
+                row[i - 1] = T::one();
+            }
+            row[degree - 1] = -poly_coeffs[i] / leading;
+        }
+        Ok(out)
+    }
+
+    /// Returns the `n×n` symmetric Pascal matrix, whose entries are the binomial
+    /// coefficients `(i + j)! / (i! j!)` (0-indexed). Its determinant is always 1.
+    /// It needs the [`One`] trait.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::pascal(3);
+    /// assert_eq!(m, Matrix::from(vec![vec![1, 1, 1], vec![1, 2, 3], vec![1, 3, 6]]).unwrap());
+    /// ```
+    pub fn pascal(n: usize) -> Self
+    where
+        T: One,
+    {
+        let mut out = Matrix::zero(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                out.entries[i * n + j] = if i == 0 || j == 0 {
+                    T::one()
+                } else {
+                    out.entries[(i - 1) * n + j] + out.entries[i * n + j - 1]
+                };
+            }
+        }
+        out
+    }
+
+    /// Returns the `n×n` Jordan block with the given eigenvalue, i.e. it's on the diagonal
+    /// and there are ones right above it.
+    /// It needs the [`One`] trait.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::jordan_block(2, 2);
+    /// assert_eq!(m, Matrix::from(vec![vec![2, 1], vec![0, 2]]).unwrap());
+    /// ```
+    pub fn jordan_block(lambda: T, n: usize) -> Self
+    where
+        T: One,
+    {
+        Self::from_fn(n, n, |i, j| {
+            if i == j {
+                lambda
+            } else if i + 1 == j {
+                T::one()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
     /// Multiplies all entries of a matrix by a scalar.
-    /// Note that it modifies the supplied matrix.
+    /// Note that it modifies the supplied matrix. It's the same as `m *= scalar`.
     /// # Example
     /// ```
     /// use matrix_basic::Matrix;
@@ -395,142 +963,229 @@ impl<T: ToMatrix> Matrix<T> {
     /// assert_eq!(m, n);
     /// ```
     pub fn mul_scalar(&mut self, scalar: T) {
-        for row in &mut self.entries {
-            for entry in row {
-                *entry = *entry * scalar;
-            }
-        }
+        *self *= scalar;
     }
 
     /// Returns the inverse of a square matrix. Throws an error if the matrix isn't square.
-    /// /// # Example
+    /// Use [`lu`](Self::lu()) directly if the decomposition is to be reused, and
+    /// [`pseudo_inverse`](Self::pseudo_inverse()) for matrices that aren't square or invertible.
+    /// # Example
     /// ```
     /// use matrix_basic::Matrix;
     /// let m = Matrix::from(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
-    /// let n = Matrix::from(vec![vec![-2.0, 1.0], vec![1.5, -0.5]]).unwrap();
-    /// assert_eq!(m.inverse(), Ok(n));
+    /// let n: Matrix<f64> = Matrix::from(vec![vec![-2.0, 1.0], vec![1.5, -0.5]]).unwrap();
+    /// let inverse = m.inverse().unwrap();
+    /// assert!(inverse.as_slice().iter().zip(n.as_slice()).all(|(x, y)| (x - y).abs() < 1e-12));
     /// ```
     pub fn inverse(&self) -> Result<Self, MatrixError>
     where
         T: Div<Output = T>,
         T: One,
-        T: PartialEq,
+        T: Pivot,
     {
         if self.is_square() {
-            // We'll use the basic technique of using an augmented matrix (in essence)
-            // Cloning is necessary as we'll be doing row operations on it.
-            let mut rows = self.entries.clone();
-            let h = self.height();
-            let w = self.width();
-            let mut out = Self::identity(h).entries;
+            self.lu().inverse()
+        } else {
+            Err(MatrixError::NotSquare)
+        }
+    }
 
-            // First we get row echelon form
-            for i in 0..(h - 1) {
-                // First check if the row has diagonal element 0, if yes, then swap.
-                if rows[i][i] == T::zero() {
-                    let mut zero_column = true;
-                    for j in (i + 1)..h {
-                        if rows[j][i] != T::zero() {
-                            rows.swap(i, j);
-                            out.swap(i, j);
-                            zero_column = false;
-                            break;
-                        }
-                    }
-                    if zero_column {
-                        return Err(MatrixError::Singular);
-                    }
-                }
-                for j in (i + 1)..h {
-                    let ratio = rows[j][i] / rows[i][i];
-                    for k in i..w {
-                        rows[j][k] = rows[j][k] - rows[i][k] * ratio;
-                    }
-                    // We cannot skip entries here as they might not be 0
-                    for k in 0..w {
-                        out[j][k] = out[j][k] - out[i][k] * ratio;
-                    }
-                }
-            }
+    /// Returns the sum of two matrices.
+    /// It'll throw an error if they don't have the same dimensions.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// let n = Matrix::from(vec![vec![2, 4], vec![6, 8]]).unwrap();
+    /// assert_eq!(m.checked_add(&m), Ok(n));
+    /// assert!(m.checked_add(&m.transpose().submatrix(0, 0)).is_err());
+    /// ```
+    pub fn checked_add(&self, other: &Self) -> Result<Self, MatrixError> {
+        self.zip_with(other, |x, y| x + y)
+    }
 
-            // Then we reduce the rows
-            for i in 0..h {
-                if rows[i][i] == T::zero() {
-                    return Err(MatrixError::Singular);
-                }
-                let divisor = rows[i][i];
-                for entry in rows[i].iter_mut().skip(i) {
-                    *entry = *entry / divisor;
-                }
-                for entry in out[i].iter_mut() {
-                    *entry = *entry / divisor;
-                }
-            }
+    /// Returns the difference of two matrices.
+    /// It'll throw an error if they don't have the same dimensions.
+    pub fn checked_sub(&self, other: &Self) -> Result<Self, MatrixError> {
+        self.zip_with(other, |x, y| x - y)
+    }
 
-            // Finally, we do upside down row reduction
-            for i in (1..h).rev() {
-                for j in (0..i).rev() {
-                    let ratio = rows[j][i];
-                    for k in 0..w {
-                        out[j][k] = out[j][k] - out[i][k] * ratio;
-                    }
+    /// Returns the product of two matrices.
+    /// It'll throw an error if the width of the first one isn't the height of the second one.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![1, 2, 3]]).unwrap();
+    /// let n = Matrix::from(vec![vec![1], vec![1], vec![1]]).unwrap();
+    /// assert_eq!(m.checked_mul(&n), Ok(Matrix::from(vec![vec![6]]).unwrap()));
+    /// assert!(m.checked_mul(&m).is_err());
+    /// ```
+    pub fn checked_mul(&self, other: &Self) -> Result<Self, MatrixError> {
+        if self.width != other.height {
+            return Err(MatrixError::DimensionMismatch {
+                left: (self.height, self.width),
+                right: (other.height, other.width),
+            });
+        }
+        // Going through the rows of the second matrix in the inner loop
+        // keeps all the memory accesses contiguous.
+        let mut out = Matrix::zero(self.height, other.width);
+        for (out_row, row) in out.row_slices_mut().zip(self.row_slices()) {
+            for (entry, other_row) in row.iter().zip(other.row_slices()) {
+                for (out_entry, other_entry) in out_row.iter_mut().zip(other_row) {
+                    *out_entry = *out_entry + *entry * *other_entry;
                 }
             }
+        }
+        Ok(out)
+    }
 
-            Ok(Matrix { entries: out })
-        } else {
-            Err(MatrixError::NotSquare)
+    /// Combines the corresponding entries of two matrices of the same dimensions.
+    fn zip_with(&self, other: &Self, f: impl Fn(T, T) -> T) -> Result<Self, MatrixError> {
+        if self.height != other.height || self.width != other.width {
+            return Err(MatrixError::DimensionMismatch {
+                left: (self.height, self.width),
+                right: (other.height, other.width),
+            });
+        }
+        Ok(Matrix {
+            entries: self
+                .entries
+                .iter()
+                .zip(&other.entries)
+                .map(|(x, y)| f(*x, *y))
+                .collect(),
+            height: self.height,
+            width: self.width,
+        })
+    }
+
+    /// Combines the corresponding entries of two matrices of the same dimensions, storing
+    /// the results in the first one.
+    fn zip_with_in_place(
+        &mut self,
+        other: &Self,
+        f: impl Fn(T, T) -> T,
+    ) -> Result<(), MatrixError> {
+        if self.height != other.height || self.width != other.width {
+            return Err(MatrixError::DimensionMismatch {
+                left: (self.height, self.width),
+                right: (other.height, other.width),
+            });
+        }
+        for (x, y) in self.entries.iter_mut().zip(&other.entries) {
+            *x = f(*x, *y);
         }
+        Ok(())
     }
 
-    // TODO: Canonical forms, eigenvalues, eigenvectors etc.
+    // TODO: Canonical forms etc.
+}
+
+impl<T: ToMatrix> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+        match self.offset(i, j) {
+            Ok(k) => &self.entries[k],
+            Err(error) => panic!("{error}"),
+        }
+    }
+}
+
+impl<T: ToMatrix> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Self::Output {
+        match self.offset(i, j) {
+            Ok(k) => &mut self.entries[k],
+            Err(error) => panic!("{error}"),
+        }
+    }
 }
 
 impl<T: Debug + ToMatrix> Display for Matrix<T> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{:?}", self.entries)
+        write!(f, "{:?}", self.rows())
     }
 }
 
-impl<T: Mul<Output = T> + ToMatrix> Mul for Matrix<T> {
-    // TODO: Implement a faster algorithm.
-    type Output = Self;
-    fn mul(self, other: Self) -> Self::Output {
-        let width = self.width();
-        if width != other.height() {
-            panic!("row length of first matrix != column length of second matrix");
-        } else {
-            let mut out = Vec::new();
-            for row in self.rows() {
-                let mut new_row = Vec::new();
-                for col in other.columns() {
-                    let mut prod = row[0] * col[0];
-                    for i in 1..width {
-                        prod = prod + (row[i] * col[i]);
-                    }
-                    new_row.push(prod)
+/// Implements a binary operator for all combinations of owned and borrowed matrices,
+/// panicking with the error of the given checked method.
+macro_rules! impl_binary_operator {
+    ($($trait:ident $method:ident $checked:ident;)*) => ($(
+        impl<T: ToMatrix> $trait<&Matrix<T>> for &Matrix<T> {
+            type Output = Matrix<T>;
+            fn $method(self, other: &Matrix<T>) -> Self::Output {
+                match self.$checked(other) {
+                    Ok(out) => out,
+                    Err(error) => panic!("{error}"),
                 }
-                out.push(new_row);
             }
-            Matrix { entries: out }
         }
-    }
+
+        impl<T: ToMatrix> $trait<Matrix<T>> for &Matrix<T> {
+            type Output = Matrix<T>;
+            fn $method(self, other: Matrix<T>) -> Self::Output {
+                self.$method(&other)
+            }
+        }
+
+        impl<T: ToMatrix> $trait<&Matrix<T>> for Matrix<T> {
+            type Output = Matrix<T>;
+            fn $method(self, other: &Matrix<T>) -> Self::Output {
+                (&self).$method(other)
+            }
+        }
+
+        impl<T: ToMatrix> $trait for Matrix<T> {
+            type Output = Matrix<T>;
+            fn $method(self, other: Matrix<T>) -> Self::Output {
+                (&self).$method(&other)
+            }
+        }
+
+    )*)
 }
 
-impl<T: Mul<Output = T> + ToMatrix> Add for Matrix<T> {
-    type Output = Self;
-    fn add(self, other: Self) -> Self::Output {
-        if self.height() == other.height() && self.width() == other.width() {
-            let mut out = self.entries.clone();
-            for (i, row) in self.rows().iter().enumerate() {
-                for (j, entry) in other.rows()[i].iter().enumerate() {
-                    out[i][j] = row[j] + *entry;
+impl_binary_operator! {
+    Add add checked_add;
+    Sub sub checked_sub;
+    Mul mul checked_mul;
+}
+
+/// Implements an entrywise compound assignment operator for owned and borrowed right hand
+/// sides, updating the entries in place and panicking if the dimensions don't match.
+macro_rules! impl_entrywise_assign_operator {
+    ($($trait:ident $method:ident $f:expr;)*) => ($(
+        impl<T: ToMatrix> $trait<&Matrix<T>> for Matrix<T> {
+            fn $method(&mut self, other: &Matrix<T>) {
+                if let Err(error) = self.zip_with_in_place(other, $f) {
+                    panic!("{error}");
                 }
             }
-            Matrix { entries: out }
-        } else {
-            panic!("provided matrices have different dimensions");
         }
+
+        impl<T: ToMatrix> $trait for Matrix<T> {
+            fn $method(&mut self, other: Matrix<T>) {
+                self.$method(&other);
+            }
+        }
+    )*)
+}
+
+impl_entrywise_assign_operator! {
+    AddAssign add_assign |x, y| x + y;
+    SubAssign sub_assign |x, y| x - y;
+}
+
+impl<T: ToMatrix> MulAssign<&Matrix<T>> for Matrix<T> {
+    fn mul_assign(&mut self, other: &Matrix<T>) {
+        // The product needs a new matrix, since the entries are still used while computing it.
+        *self = &*self * other;
+    }
+}
+
+impl<T: ToMatrix> MulAssign for Matrix<T> {
+    fn mul_assign(&mut self, other: Matrix<T>) {
+        *self = &*self * &other;
     }
 }
 
@@ -538,26 +1193,136 @@ impl<T: ToMatrix> Neg for Matrix<T> {
     type Output = Self;
     fn neg(self) -> Self::Output {
         let mut out = self;
-        for row in &mut out.entries {
-            for entry in row {
-                *entry = -*entry;
-            }
+        for entry in &mut out.entries {
+            *entry = -*entry;
         }
         out
     }
 }
 
-impl<T: ToMatrix> Sub for Matrix<T> {
+impl<T: ToMatrix> Neg for &Matrix<T> {
+    type Output = Matrix<T>;
+    fn neg(self) -> Self::Output {
+        -self.clone()
+    }
+}
+
+impl<T: ToMatrix> MulAssign<T> for Matrix<T> {
+    fn mul_assign(&mut self, scalar: T) {
+        for entry in &mut self.entries {
+            *entry = *entry * scalar;
+        }
+    }
+}
+
+impl<T: ToMatrix> Mul<T> for Matrix<T> {
     type Output = Self;
-    fn sub(self, other: Self) -> Self::Output {
-        if self.height() == other.height() && self.width() == other.width() {
-            self + -other
-        } else {
-            panic!("provided matrices have different dimensions");
+    fn mul(mut self, scalar: T) -> Self::Output {
+        self *= scalar;
+        self
+    }
+}
+
+impl<T: ToMatrix> Mul<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+    fn mul(self, scalar: T) -> Self::Output {
+        self.clone() * scalar
+    }
+}
+
+impl<T: ToMatrix + Div<Output = T>> DivAssign<T> for Matrix<T> {
+    fn div_assign(&mut self, scalar: T) {
+        for entry in &mut self.entries {
+            *entry = *entry / scalar;
         }
     }
 }
 
+impl<T: ToMatrix + Div<Output = T>> Div<T> for Matrix<T> {
+    type Output = Self;
+    fn div(mut self, scalar: T) -> Self::Output {
+        self /= scalar;
+        self
+    }
+}
+
+impl<T: ToMatrix + Div<Output = T>> Div<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+    fn div(self, scalar: T) -> Self::Output {
+        self.clone() / scalar
+    }
+}
+
+/// Creates a matrix from a 2D array, so the rows are checked to be of the same size at
+/// compile time. Note that `Matrix::from` refers to [`Matrix::from()`], so [`Into::into()`]
+/// has to be used instead, or the [`matrix!`] macro.
+/// # Example
+/// ```
+/// use matrix_basic::Matrix;
+/// let m: Matrix<i32> = [[1, 2], [3, 4]].into();
+/// assert_eq!(m, Matrix::from(vec![vec![1, 2], vec![3, 4]]).unwrap());
+/// ```
+impl<T: ToMatrix, const H: usize, const W: usize> From<[[T; W]; H]> for Matrix<T> {
+    fn from(entries: [[T; W]; H]) -> Self {
+        Matrix {
+            entries: entries.into_iter().flatten().collect(),
+            height: H,
+            width: W,
+        }
+    }
+}
+
+/// Collects rows into a matrix. An empty iterator gives a 0×0 matrix.
+/// It'll panic if all the rows aren't of the same size, see [`Matrix::from()`]
+/// for the fallible version.
+/// # Example
+/// ```
+/// use matrix_basic::Matrix;
+/// let m: Matrix<i32> = (1..=3).map(|i| vec![i, i * i]).collect();
+/// assert_eq!(m, Matrix::from(vec![vec![1, 1], vec![2, 4], vec![3, 9]]).unwrap());
+/// ```
+impl<T: ToMatrix, R: IntoIterator<Item = T>> FromIterator<R> for Matrix<T> {
+    fn from_iter<I: IntoIterator<Item = R>>(rows: I) -> Self {
+        let mut entries = Vec::new();
+        let mut height = 0;
+        let mut width = 0;
+        for row in rows {
+            entries.extend(row);
+            height += 1;
+            if height == 1 {
+                width = entries.len();
+            } else if entries.len() != height * width {
+                panic!("{}", MatrixError::UnequalRows);
+            }
+        }
+        Matrix {
+            entries,
+            height,
+            width,
+        }
+    }
+}
+
+/// Creates a [`Matrix`] with rows separated by semicolons and entries separated by commas.
+/// The rows are checked to be of the same size at compile time.
+/// # Example
+/// ```
+/// use matrix_basic::{matrix, Matrix};
+/// let m = matrix![1, 2, 3; 4, 5, 6];
+/// assert_eq!(m, Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap());
+/// ```
+/// Rows of different sizes don't compile:
+/// ```compile_fail
+/// use matrix_basic::matrix;
+/// let m = matrix![1, 2, 3; 4, 5];
+/// ```
+#[macro_export]
+macro_rules! matrix {
+    ($($($entry:expr),+ $(,)?);+ $(;)?) => {
+        <$crate::Matrix<_> as ::core::convert::From<_>>::from([$([$($entry),+]),+])
+    };
+}
+
 /// Trait for conversion between matrices of different types.
 /// It only has a [`matrix_from()`](Self::matrix_from()) method.
 /// This is needed since negative trait bound are not supported in stable Rust
@@ -585,15 +1350,11 @@ pub trait MatrixFrom<T: ToMatrix> {
 /// `S` implements [`From(T)`]. Look at [`matrix_into`](Self::matrix_into()).
 impl<T: ToMatrix, S: ToMatrix + From<T>> MatrixFrom<T> for Matrix<S> {
     fn matrix_from(input: Matrix<T>) -> Self {
-        let mut out = Vec::new();
-        for row in input.entries {
-            let mut new_row: Vec<S> = Vec::new();
-            for entry in row {
-                new_row.push(entry.into());
-            }
-            out.push(new_row)
+        Matrix {
+            entries: input.entries.into_iter().map(S::from).collect(),
+            height: input.height,
+            width: input.width,
         }
-        Matrix { entries: out }
     }
 }
 
@@ -0,0 +1,153 @@
+use crate::{
+    decompositions::Echelon, errors::MatrixError, subspaces::nullspace_basis, Matrix, Pivot,
+    ToMatrix,
+};
+use num::traits::One;
+use std::ops::Div;
+
+/// Set of all solutions of a linear system `AX = B`, as returned by
+/// [`Matrix::solution_set()`](crate::Matrix::solution_set()).
+#[derive(PartialEq, Debug, Clone)]
+pub enum SolutionSet<T: ToMatrix> {
+    /// The system has no solutions.
+    Inconsistent,
+    /// The solutions are exactly `X = P + NY` for arbitrary `Y`, where `P` is the particular
+    /// solution and the columns of `N` form a basis of the nullspace of `A`.
+    Consistent {
+        /// A particular solution, with all the free variables set to zero.
+        particular: Matrix<T>,
+        /// Matrix whose columns form a basis of the solutions of `AX = 0`.
+        /// It has no columns if the solution is unique.
+        nullspace: Matrix<T>,
+        /// Columns of `A` containing pivots, i.e. the dependent variables.
+        pivot_columns: Vec<usize>,
+        /// Columns of `A` without pivots, i.e. the free variables.
+        free_columns: Vec<usize>,
+    },
+}
+
+impl<T: ToMatrix> Matrix<T> {
+    /// Solves `AX = B` for `X` over a field i.e. needs [`One`] and [`Div`] traits, where `A` is
+    /// the provided matrix and `B` is given. All columns of `B` are solved for at once by
+    /// elimination, which is faster and more accurate than multiplying with the
+    /// [`inverse`](Self::inverse()). `A` can be of any shape. If the system has many solutions,
+    /// a particular one is returned, with all the free variables set to zero.
+    /// Use [`lu`](Self::lu()) directly if the decomposition is to be reused.
+    /// It'll throw an error if the system is inconsistent, or if the heights of `A` and `B` don't
+    /// match. Like the rank, the pivots and the consistency are decided up to the
+    /// [tolerance](Pivot::tolerance()) of [`Pivot`], computed from the augmented matrix `[A | B]`,
+    /// which is exact for exact types. It's the same tolerance as in
+    /// [`solution_set`](Self::solution_set()), so both agree on whether a system is consistent.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let a = Matrix::from(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+    /// let b = Matrix::from(vec![vec![2.0, 1.0], vec![4.0, 2.0]]).unwrap();
+    /// let x = Matrix::from(vec![vec![2.0, 1.0], vec![0.0, 0.0]]).unwrap();
+    /// assert_eq!(a.solve(&b), Ok(x));
+    /// ```
+    pub fn solve(&self, rhs: &Self) -> Result<Self, MatrixError>
+    where
+        T: One,
+        T: Pivot,
+        T: Div<Output = T>,
+    {
+        if rhs.height() != self.height() {
+            return Err(MatrixError::DimensionMismatch {
+                left: (self.height(), self.width()),
+                right: (rhs.height(), rhs.width()),
+            });
+        }
+        // The tolerance is the one used for the augmented matrix [A | B] in `solution_set`,
+        // so that both agree on the rank and the consistency.
+        let size = self.height().max(self.width() + rhs.width());
+        let tolerance = T::tolerance(&[self.as_slice(), rhs.as_slice()].concat(), size);
+        let lu = self.clone().into_lu_with_tolerance(tolerance);
+        let rank = lu.rank();
+        let y = lu.forward_substitute(rhs);
+        // The rows of U below the rank are zero, so the system is only
+        // consistent if the same holds for Y.
+        if y.row_slices()
+            .skip(rank)
+            .flatten()
+            .any(|entry| !entry.is_negligible(&tolerance))
+        {
+            return Err(MatrixError::Singular);
+        }
+
+        // Back substitution, with the free variables set to zero.
+        let mut out = Self::zero(self.width(), rhs.width());
+        for (i, &p) in lu.pivots.iter().enumerate().rev() {
+            let u_row = lu.u.row_slice(i);
+            let mut row = y.row_slice(i).to_vec();
+            for (j, &ratio) in u_row.iter().enumerate().skip(p + 1) {
+                for (entry, other) in row.iter_mut().zip(out.row_slice(j)) {
+                    *entry = *entry - *other * ratio;
+                }
+            }
+            let divisor = u_row[p];
+            for entry in row.iter_mut() {
+                *entry = *entry / divisor;
+            }
+            out.row_slice_mut(p).copy_from_slice(&row);
+        }
+        Ok(out)
+    }
+
+    /// Returns the set of all solutions of `AX = B` over a field i.e. needs [`One`] and [`Div`]
+    /// traits, where `A` is the provided matrix and `B` is given. It's found from the reduced
+    /// row echelon form of the augmented matrix `[A | B]`, see [`SolutionSet`] for the details.
+    /// Like in [`solve`](Self::solve()), pivots that are [negligible](Pivot::is_negligible())
+    /// compared to the [tolerance](Pivot::tolerance()) of the augmented matrix are treated as
+    /// zero, so consistency is only checked exactly for exact types.
+    /// It'll throw an error if the heights of `A` and `B` don't match.
+    /// # Example
+    /// ```
+    /// use matrix_basic::{Matrix, SolutionSet};
+    /// let a = Matrix::from(vec![vec![1.0, 2.0, 1.0], vec![2.0, 4.0, 0.0]]).unwrap();
+    /// let b = Matrix::from(vec![vec![3.0], vec![2.0]]).unwrap();
+    /// let SolutionSet::Consistent { particular, nullspace, free_columns, .. } = a.solution_set(&b).unwrap() else {
+    ///     panic!("the system is consistent");
+    /// };
+    /// assert_eq!(particular, Matrix::from(vec![vec![1.0], vec![0.0], vec![2.0]]).unwrap());
+    /// assert_eq!(nullspace, Matrix::from(vec![vec![-2.0], vec![1.0], vec![0.0]]).unwrap());
+    /// assert_eq!(free_columns, vec![1]);
+    /// ```
+    pub fn solution_set(&self, rhs: &Self) -> Result<SolutionSet<T>, MatrixError>
+    where
+        T: One,
+        T: Pivot,
+        T: Div<Output = T>,
+    {
+        if rhs.height() != self.height() {
+            return Err(MatrixError::DimensionMismatch {
+                left: (self.height(), self.width()),
+                right: (rhs.height(), rhs.width()),
+            });
+        }
+        let w = self.width();
+        let Echelon {
+            matrix: reduced,
+            pivots: pivot_columns,
+            ..
+        } = Matrix::hstack(&[self, rhs])?.reduced_echelon();
+        // A pivot in the augmented part means a row 0 = b with b non-zero.
+        if pivot_columns.iter().any(|&p| p >= w) {
+            return Ok(SolutionSet::Inconsistent);
+        }
+        let free_columns: Vec<usize> = (0..w).filter(|j| !pivot_columns.contains(j)).collect();
+
+        let mut particular = Self::zero(w, rhs.width());
+        for (row, &p) in reduced.row_slices().zip(&pivot_columns) {
+            particular.row_slice_mut(p).copy_from_slice(&row[w..]);
+        }
+        let nullspace = nullspace_basis(&reduced, &pivot_columns, &free_columns);
+
+        Ok(SolutionSet::Consistent {
+            particular,
+            nullspace,
+            pivot_columns,
+            free_columns,
+        })
+    }
+}
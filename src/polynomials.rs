@@ -0,0 +1,164 @@
+use crate::{errors::MatrixError, Matrix, ToMatrix};
+use num::{traits::One, Integer};
+use std::ops::Div;
+
+impl<T: ToMatrix> Matrix<T> {
+    /// Returns the determinant and the characteristic polynomial of a square matrix over any
+    /// commutative ring i.e. only needs the [`One`] trait. This uses the division-free
+    /// Berkowitz algorithm, which takes O(n⁴) ring operations.
+    /// The polynomial is `det(xI - A)` and its coefficients are given in increasing order
+    /// of degree, so the last one is always `1`.
+    /// It'll throw an error if the provided matrix isn't square.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// assert_eq!(m.berkowitz(), Ok((-2, vec![-2, -5, 1])));
+    /// ```
+    pub fn berkowitz(&self) -> Result<(T, Vec<T>), MatrixError>
+    where
+        T: One,
+    {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare);
+        }
+        let a = &self.entries;
+        let n = self.height();
+        // Coefficients of the characteristic polynomial of the leading principal
+        // submatrix processed so far, in decreasing order of degree.
+        let mut coeffs = vec![T::one()];
+        for r in 0..n {
+            // The submatrix of size r + 1 is split as [[A_r, C], [R, a_rr]], and we build
+            // the first column of the Toeplitz matrix, i.e. 1, -a_rr, -RC, -RA_rC, ...
+            let mut toeplitz = vec![T::one(), -a[r * n + r]];
+            let mut column: Vec<T> = (0..r).map(|i| a[i * n + r]).collect();
+            for _ in 0..r {
+                let mut dot = T::zero();
+                for (i, entry) in column.iter().enumerate() {
+                    dot = dot + a[r * n + i] * *entry;
+                }
+                toeplitz.push(-dot);
+                // Now multiply the column by A_r for the next power.
+                column = (0..r)
+                    .map(|i| {
+                        let mut out = T::zero();
+                        for (j, entry) in column.iter().enumerate() {
+                            out = out + a[i * n + j] * *entry;
+                        }
+                        out
+                    })
+                    .collect();
+            }
+            // Multiply the lower triangular Toeplitz matrix with the old coefficients.
+            coeffs = (0..(r + 2))
+                .map(|i| {
+                    let mut out = T::zero();
+                    for (j, entry) in coeffs.iter().enumerate().take(i + 1) {
+                        out = out + toeplitz[i - j] * *entry;
+                    }
+                    out
+                })
+                .collect();
+        }
+        coeffs.reverse();
+
+        // The constant term is det(-A) = (-1)^n det(A).
+        let det = if n.is_even() { coeffs[0] } else { -coeffs[0] };
+        Ok((det, coeffs))
+    }
+
+    /// Returns the coefficients of the characteristic polynomial `det(xI - A)` of a square
+    /// matrix, in increasing order of degree. It only needs the [`One`] trait.
+    /// See [`berkowitz`](Self::berkowitz()) for details.
+    /// It'll throw an error if the provided matrix isn't square.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![2, 1], vec![0, 3]]).unwrap();
+    /// assert_eq!(m.characteristic_polynomial(), Ok(vec![6, -5, 1]));
+    /// ```
+    pub fn characteristic_polynomial(&self) -> Result<Vec<T>, MatrixError>
+    where
+        T: One,
+    {
+        self.berkowitz().map(|(_, coeffs)| coeffs)
+    }
+
+    /// Returns the coefficients of the minimal polynomial of a square matrix over a field,
+    /// in increasing order of degree, i.e. needs [`One`] and [`Div`] traits.
+    /// It's found as the first linear dependency among the powers `I, A, A², ...`.
+    /// This is meant for exact types, with floating point types the dependency is usually
+    /// lost to rounding, and the characteristic polynomial is returned instead.
+    /// It'll throw an error if the provided matrix isn't square.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::diagonal_matrix(vec![2.0, 2.0, 3.0]);
+    /// assert_eq!(m.minimal_polynomial(), Ok(vec![6.0, -5.0, 1.0]));
+    /// ```
+    pub fn minimal_polynomial(&self) -> Result<Vec<T>, MatrixError>
+    where
+        T: One,
+        T: PartialEq,
+        T: Div<Output = T>,
+    {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare);
+        }
+        let n = self.height();
+        // Each element holds a reduced power as a flat vector, the combination of powers
+        // that gives it, and the position of its pivot.
+        let mut basis: Vec<(Vec<T>, Vec<T>, usize)> = Vec::new();
+        let mut power = Self::identity(n);
+        for k in 0..=n {
+            let mut vector = power.entries.clone();
+            let mut combination = vec![T::zero(); k + 1];
+            combination[k] = T::one();
+            for (other, other_combination, p) in &basis {
+                if vector[*p] == T::zero() {
+                    continue;
+                }
+                let ratio = vector[*p] / other[*p];
+                for (entry, other_entry) in vector.iter_mut().zip(other) {
+                    *entry = *entry - *other_entry * ratio;
+                }
+                for (entry, other_entry) in combination.iter_mut().zip(other_combination) {
+                    *entry = *entry - *other_entry * ratio;
+                }
+            }
+            // The combination stays monic as the earlier ones only involve lower powers.
+            match vector.iter().position(|entry| *entry != T::zero()) {
+                Some(p) => basis.push((vector, combination, p)),
+                None => return Ok(combination),
+            }
+            power *= self;
+        }
+        self.characteristic_polynomial()
+    }
+
+    /// Returns the matrix `p(A)` for a square matrix `A` and a polynomial `p` whose
+    /// coefficients are given in increasing order of degree. It needs the [`One`] trait.
+    /// This uses Horner's scheme.
+    /// It'll throw an error if the provided matrix isn't square.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// let p = m.characteristic_polynomial().unwrap();
+    /// assert_eq!(m.eval_polynomial(&p), Ok(Matrix::zero(2, 2)));
+    /// ```
+    pub fn eval_polynomial(&self, coeffs: &[T]) -> Result<Self, MatrixError>
+    where
+        T: One,
+    {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare);
+        }
+        let n = self.height();
+        let mut out = Self::zero(n, n);
+        for coeff in coeffs.iter().rev() {
+            out = out * self + Self::diagonal_matrix(vec![*coeff; n]);
+        }
+        Ok(out)
+    }
+}
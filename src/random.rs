@@ -0,0 +1,188 @@
+use crate::{errors::MatrixError, Matrix, ToMatrix};
+use num::traits::One;
+use rand::{distributions::Distribution, Rng};
+
+impl<T: ToMatrix> Matrix<T> {
+    /// Returns a matrix of the given shape whose entries are sampled independently
+    /// from the provided distribution.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// use rand::{distributions::Uniform, rngs::StdRng, SeedableRng};
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let m = Matrix::random(2, 3, &mut rng, Uniform::new_inclusive(-5, 5));
+    /// assert!(m.as_slice().iter().all(|x| (-5..=5).contains(x)));
+    /// ```
+    pub fn random<R, D>(height: usize, width: usize, rng: &mut R, distribution: D) -> Self
+    where
+        R: Rng + ?Sized,
+        D: Distribution<T>,
+    {
+        Self::from_fn(height, width, |_, _| distribution.sample(rng))
+    }
+
+    /// Returns a matrix of the given shape where each entry is, independently, sampled from the
+    /// provided distribution with probability `density`, and zero otherwise.
+    /// It'll panic if `density` isn't between 0 and 1.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// use rand::{distributions::Uniform, rngs::StdRng, SeedableRng};
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let m = Matrix::random_sparse(10, 10, 0.0, &mut rng, Uniform::new(1, 10));
+    /// assert_eq!(m, Matrix::zero(10, 10));
+    /// ```
+    pub fn random_sparse<R, D>(
+        height: usize,
+        width: usize,
+        density: f64,
+        rng: &mut R,
+        distribution: D,
+    ) -> Self
+    where
+        R: Rng + ?Sized,
+        D: Distribution<T>,
+    {
+        Self::from_fn(height, width, |_, _| {
+            if rng.gen_bool(density) {
+                distribution.sample(rng)
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    /// Returns a random `n×n` matrix with the given determinant. It's the product of unit lower
+    /// and upper triangular matrices with off-diagonal entries sampled from the provided
+    /// distribution, with the determinant put in a corner, after which the rows and columns are
+    /// shuffled (flipping signs to keep the determinant). So, the entries stay integers when the
+    /// sampled values are, which makes it suitable for generating integer matrices with
+    /// a prescribed (e.g. unit) determinant.
+    /// It needs the [`One`] trait.
+    /// It'll throw an error if `n` is zero and `det` isn't one, since the `0×0` matrix has
+    /// determinant one.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// use rand::{distributions::Uniform, rngs::StdRng, SeedableRng};
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let m = Matrix::random_with_det(4, -3, &mut rng, Uniform::new_inclusive(-2, 2)).unwrap();
+    /// assert_eq!(m.det_bareiss(), Ok(-3));
+    /// ```
+    pub fn random_with_det<R, D>(
+        n: usize,
+        det: T,
+        rng: &mut R,
+        distribution: D,
+    ) -> Result<Self, MatrixError>
+    where
+        T: One,
+        T: PartialEq,
+        R: Rng + ?Sized,
+        D: Distribution<T>,
+    {
+        if n == 0 {
+            return if det == T::one() {
+                Ok(Self::zero(0, 0))
+            } else {
+                Err(MatrixError::Empty)
+            };
+        }
+        let mut l = Self::identity(n);
+        let mut u = Self::identity(n);
+        for i in 0..n {
+            for j in 0..i {
+                l.entries[i * n + j] = distribution.sample(rng);
+                u.entries[j * n + i] = distribution.sample(rng);
+            }
+        }
+        u.entries[0] = det;
+        let mut out = &l * &u;
+        out.shuffle_rows(rng);
+        let mut out = out.transpose();
+        out.shuffle_rows(rng);
+        Ok(out.transpose())
+    }
+
+    /// Shuffles the rows randomly, negating a row at each swap so that the determinant stays
+    /// the same.
+    fn shuffle_rows<R>(&mut self, rng: &mut R)
+    where
+        R: Rng + ?Sized,
+    {
+        for i in (1..self.height()).rev() {
+            let j = rng.gen_range(0..=i);
+            if j != i {
+                self.swap_rows(i, j);
+                for entry in self.row_slice_mut(i) {
+                    *entry = -*entry;
+                }
+            }
+        }
+    }
+}
+
+impl Matrix<f64> {
+    /// Returns a random `n×n` orthogonal matrix, distributed uniformly with respect to the
+    /// Haar measure. It's the `Q` factor of the [QR decomposition](Self::qr()) of a matrix with
+    /// standard normal entries, with the signs of its columns fixed so that `R` has a
+    /// positive diagonal.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let q = Matrix::random_orthogonal(3, &mut rng);
+    /// let product = q.transpose() * q;
+    /// let identity: Matrix<f64> = Matrix::identity(3);
+    /// assert!(product.as_slice().iter().zip(identity.as_slice()).all(|(x, y)| (x - y).abs() < 1e-12));
+    /// ```
+    pub fn random_orthogonal<R>(n: usize, rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        let qr = Self::from_fn(n, n, |_, _| standard_normal(rng)).qr();
+        let mut q = qr.q;
+        for (j, &r) in qr.r.entries.iter().step_by(n + 1).enumerate() {
+            if r < 0.0 {
+                for row in q.row_slices_mut() {
+                    row[j] = -row[j];
+                }
+            }
+        }
+        q
+    }
+
+    /// Returns a random `n×n` symmetric positive definite matrix. It's `BBᵀ + nI` where `B`
+    /// has standard normal entries, so all of its eigenvalues are at least `n`.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let m = Matrix::random_spd(4, &mut rng);
+    /// assert!(m.cholesky().is_ok());
+    /// ```
+    pub fn random_spd<R>(n: usize, rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        let b = Self::from_fn(n, n, |_, _| standard_normal(rng));
+        let mut out = &b * &b.transpose();
+        for entry in out.entries.iter_mut().step_by(n + 1) {
+            *entry += n as f64;
+        }
+        out
+    }
+}
+
+/// Returns a sample from the standard normal distribution using the Box-Muller transform.
+fn standard_normal<R>(rng: &mut R) -> f64
+where
+    R: Rng + ?Sized,
+{
+    // Shifting to (0, 1] avoids taking the logarithm of zero.
+    let u: f64 = 1.0 - rng.gen::<f64>();
+    let v: f64 = rng.gen();
+    (-2.0 * u.ln()).sqrt() * (2.0 * std::f64::consts::PI * v).cos()
+}
@@ -0,0 +1,130 @@
+use crate::{Matrix, Pivot, ToMatrix};
+use num::traits::One;
+use std::ops::Div;
+
+impl<T: ToMatrix> Matrix<T> {
+    /// Returns the rank of a matrix over a field i.e. needs [`One`] and [`Div`] traits.
+    /// For floating point types, it's the numerical rank, as pivots that are
+    /// [negligible](Pivot::is_negligible()) are treated as zero.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+    /// assert_eq!(m.rank(), 1);
+    /// ```
+    pub fn rank(&self) -> usize
+    where
+        T: One,
+        T: Pivot,
+        T: Div<Output = T>,
+    {
+        self.clone().into_rank_revealing_lu().rank()
+    }
+
+    /// Returns the indices of the columns containing pivots in the row echelon form of a matrix
+    /// over a field i.e. needs [`One`] and [`Div`] traits. The corresponding columns of the
+    /// original matrix form a maximal linearly independent subset of its columns.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![1.0, 2.0, 0.0], vec![2.0, 4.0, 1.0]]).unwrap();
+    /// assert_eq!(m.pivot_columns(), vec![0, 2]);
+    /// ```
+    pub fn pivot_columns(&self) -> Vec<usize>
+    where
+        T: One,
+        T: Pivot,
+        T: Div<Output = T>,
+    {
+        self.clone().into_rank_revealing_lu().pivots
+    }
+
+    /// Returns a matrix whose columns form a basis of the nullspace of a matrix over a field
+    /// i.e. needs [`One`] and [`Div`] traits. There's one basis vector for each column without a
+    /// pivot (see [`pivot_columns`](Self::pivot_columns())), which has a one in that position
+    /// and zeros in the other non-pivot positions.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![1.0, 2.0, 0.0], vec![2.0, 4.0, 1.0]]).unwrap();
+    /// let n = Matrix::from(vec![vec![-2.0], vec![1.0], vec![0.0]]).unwrap();
+    /// assert_eq!(m.nullspace(), n);
+    /// ```
+    pub fn nullspace(&self) -> Self
+    where
+        T: One,
+        T: Pivot,
+        T: Div<Output = T>,
+    {
+        let echelon = self.reduced_echelon();
+        let free_columns: Vec<usize> = (0..self.width())
+            .filter(|j| !echelon.pivots.contains(j))
+            .collect();
+        nullspace_basis(&echelon.matrix, &echelon.pivots, &free_columns)
+    }
+
+    /// Returns a matrix whose columns form a basis of the column space of a matrix over a field
+    /// i.e. needs [`One`] and [`Div`] traits. The basis consists of the columns of the original
+    /// matrix given by [`pivot_columns`](Self::pivot_columns()).
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![1.0, 2.0, 0.0], vec![2.0, 4.0, 1.0]]).unwrap();
+    /// let n = Matrix::from(vec![vec![1.0, 0.0], vec![2.0, 1.0]]).unwrap();
+    /// assert_eq!(m.column_space(), n);
+    /// ```
+    pub fn column_space(&self) -> Self
+    where
+        T: One,
+        T: Pivot,
+        T: Div<Output = T>,
+    {
+        let pivot_columns = self.pivot_columns();
+        Matrix::from_fn(self.height(), pivot_columns.len(), |i, j| {
+            self.entries[i * self.width + pivot_columns[j]]
+        })
+    }
+
+    /// Returns a matrix whose rows form a basis of the row space of a matrix over a field
+    /// i.e. needs [`One`] and [`Div`] traits. The basis consists of the non-zero rows of the
+    /// reduced row echelon form.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![1.0, 2.0, 0.0], vec![2.0, 4.0, 1.0]]).unwrap();
+    /// let n = Matrix::from(vec![vec![1.0, 2.0, 0.0], vec![0.0, 0.0, 1.0]]).unwrap();
+    /// assert_eq!(m.row_space(), n);
+    /// ```
+    pub fn row_space(&self) -> Self
+    where
+        T: One,
+        T: Pivot,
+        T: Div<Output = T>,
+    {
+        let echelon = self.reduced_echelon();
+        let mut out = echelon.matrix;
+        out.height = echelon.pivots.len();
+        out.entries.truncate(out.height * out.width);
+        out
+    }
+}
+
+/// Returns the basis of the nullspace read off from a matrix in reduced row echelon form,
+/// as the columns of a matrix. Only the entries in the first `pivot_columns.len()` rows
+/// and in the given columns are looked at, so it also works for augmented matrices.
+pub(crate) fn nullspace_basis<T: ToMatrix + One>(
+    reduced: &Matrix<T>,
+    pivot_columns: &[usize],
+    free_columns: &[usize],
+) -> Matrix<T> {
+    let w = pivot_columns.len() + free_columns.len();
+    let k = free_columns.len();
+    let mut out = Matrix::zero(w, k);
+    for (j, &f) in free_columns.iter().enumerate() {
+        out.entries[f * k + j] = T::one();
+        for (row, &p) in reduced.row_slices().zip(pivot_columns) {
+            out.entries[p * k + j] = -row[f];
+        }
+    }
+    out
+}
@@ -1,5 +1,16 @@
 #[cfg(test)]
-use crate::Matrix;
+use crate::{Matrix, MatrixInto};
+
+#[cfg(test)]
+fn approx_eq(a: &Matrix<f64>, b: &Matrix<f64>) -> bool {
+    a.height() == b.height()
+        && a.width() == b.width()
+        && a.rows()
+            .iter()
+            .flatten()
+            .zip(b.rows().iter().flatten())
+            .all(|(x, y)| (x - y).abs() < 1e-9)
+}
 
 #[test]
 fn mul_test() {
@@ -44,6 +55,43 @@ fn det_trace_test() {
     assert_eq!(a.trace(), Ok(14));
 }
 
+#[test]
+fn bareiss_test() {
+    let a = Matrix::from(vec![
+        vec![0, 2, -1, 3],
+        vec![4, 1, 0, -2],
+        vec![3, 3, 5, 1],
+        vec![-1, 0, 2, 7],
+    ])
+    .unwrap();
+    let b = Matrix::from(vec![vec![1, 2, 3], vec![2, 4, 6], vec![0, 1, 1]]).unwrap();
+    let c = Matrix::from(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+
+    assert_eq!(a.det_bareiss(), a.det());
+    assert_eq!(b.det_bareiss(), Ok(0));
+    assert!(c.det_bareiss().is_err());
+}
+
+#[test]
+fn berkowitz_test() {
+    let a = Matrix::from(vec![
+        vec![0, 2, -1, 3],
+        vec![4, 1, 0, -2],
+        vec![3, 3, 5, 1],
+        vec![-1, 0, 2, 7],
+    ])
+    .unwrap();
+    let b = Matrix::from(vec![vec![2, 0, 0], vec![1, 3, 0], vec![4, 5, 6]]).unwrap();
+    let c = Matrix::from(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+
+    let (det, coeffs) = a.berkowitz().unwrap();
+    assert_eq!(Ok(det), a.det());
+    assert_eq!(coeffs.len(), 5);
+    assert_eq!(coeffs[3], -a.trace().unwrap());
+    assert_eq!(b.berkowitz(), Ok((36, vec![-36, 36, -11, 1])));
+    assert!(c.berkowitz().is_err());
+}
+
 #[test]
 fn zero_one_diag_test() {
     let a = Matrix::from(vec![vec![0, 0, 0], vec![0, 0, 0]]).unwrap();
@@ -58,12 +106,48 @@ fn zero_one_diag_test() {
 fn echelon_test() {
     let m = Matrix::from(vec![vec![1.0, 2.0, 3.0], vec![1.0, 0.0, 1.0]]).unwrap();
     let a = Matrix::from(vec![vec![1.0, 2.0, 3.0], vec![0.0, -2.0, -2.0]]).unwrap();
-    let b = Matrix::from(vec![vec![1.0, 0.0, 0.0], vec![1.0, -2.0, 0.0]]).unwrap();
-    let c = Matrix::from(vec![vec![1.0, 2.0, 3.0], vec![0.0, 1.0, 1.0]]).unwrap();
+    let b = Matrix::from(vec![vec![3.0, 0.0, 0.0], vec![1.0, 2.0 / 3.0, 0.0]]).unwrap();
+    let c = Matrix::from(vec![vec![1.0, 0.0, 1.0], vec![0.0, 1.0, 1.0]]).unwrap();
 
     assert_eq!(m.row_echelon(), a);
-    assert_eq!(m.column_echelon(), b);
+    assert!(approx_eq(&m.column_echelon(), &b));
     assert_eq!(m.reduced_row_echelon(), c);
+
+    let n = Matrix::from(vec![
+        vec![0, 1, 2, 1],
+        vec![0, 2, 4, 2],
+        vec![0, 0, 0, 0],
+        vec![0, 1, 2, 3],
+    ])
+    .unwrap();
+    let d = Matrix::from(vec![
+        vec![0, 1, 2, 0],
+        vec![0, 0, 0, 1],
+        vec![0, 0, 0, 0],
+        vec![0, 0, 0, 0],
+    ])
+    .unwrap();
+    let n: Matrix<num::Rational64> = n.matrix_into();
+    let echelon = n.reduced_echelon();
+    assert_eq!(echelon.matrix(), &d.matrix_into());
+    assert_eq!(echelon.pivots(), &vec![1, 3]);
+    assert_eq!(echelon.rank(), 2);
+    assert_eq!(echelon.permutation().len(), 4);
+    assert_eq!(
+        Matrix::<f64>::zero(2, 3).reduced_row_echelon(),
+        Matrix::zero(2, 3)
+    );
+
+    // Eliminated entries are exactly zero, even when rounding errors would leave residues.
+    let p = Matrix::from(vec![
+        vec![0.1, 0.2, 0.3],
+        vec![0.3, 0.7, 0.1],
+        vec![0.7, 0.1, 0.9],
+    ])
+    .unwrap();
+    let u = p.row_echelon();
+    assert_eq!((u[(1, 0)], u[(2, 0)], u[(2, 1)]), (0.0, 0.0, 0.0));
+    assert_eq!(p.reduced_row_echelon(), Matrix::identity(3));
 }
 
 #[test]
@@ -71,7 +155,6 @@ fn conversion_test() {
     let a = Matrix::from(vec![vec![1, 2, 3], vec![0, 1, 2]]).unwrap();
     let b = Matrix::from(vec![vec![1.0, 2.0, 3.0], vec![0.0, 1.0, 2.0]]).unwrap();
 
-    use crate::MatrixInto;
     assert_eq!(b, a.clone().matrix_into());
 
     use crate::MatrixFrom;
@@ -97,5 +180,1097 @@ fn inverse_test() {
 
     println!("{:?}", a.inverse());
     assert!(a.inverse().is_err());
-    assert_eq!(b.inverse(), Ok(c));
+    assert!(approx_eq(&b.inverse().unwrap(), &c));
+    // Exact types still give the exact inverse
+    let d: Matrix<num::Rational64> =
+        Matrix::from(vec![vec![1, 2, 3], vec![0, 1, 4], vec![5, 6, 0]])
+            .unwrap()
+            .matrix_into();
+    let e: Matrix<num::Rational64> =
+        Matrix::from(vec![vec![-24, 18, 5], vec![20, -15, -4], vec![-5, 4, 1]])
+            .unwrap()
+            .matrix_into();
+    assert_eq!(d.inverse(), Ok(e));
+}
+
+#[test]
+fn pivot_test() {
+    use crate::Pivot;
+    use num::Rational64;
+
+    let a = Matrix::from(vec![vec![1e-20, 1.0], vec![1.0, 1.0]]).unwrap();
+    let b = Matrix::from(vec![vec![1.0], vec![2.0]]).unwrap();
+    let x = Matrix::from(vec![vec![1.0], vec![1.0]]).unwrap();
+    let c = Matrix::from(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+    let d = Matrix::from(vec![vec![1.0, 2.0], vec![0.0, -2.0]]).unwrap();
+
+    assert_eq!(f64::pivot(&[1.0, -3.0, 2.0]), Some(1));
+    assert_eq!(f64::pivot(&[0.0, 0.0]), None);
+    assert_eq!(f64::pivot(&[1.0, f64::NAN, 2.0]), Some(1));
+    let nan = Matrix::from(vec![vec![f64::NAN, 1.0], vec![1.0, 1.0]]).unwrap();
+    assert!(nan.det_in_field().unwrap().is_nan());
+    assert!(nan.inverse().unwrap().as_slice().iter().any(|x| x.is_nan()));
+    assert_eq!(Rational64::pivot(&[0.into(), 1.into(), 3.into()]), Some(1));
+    // Without pivoting, the tiny entry ruins the solution.
+    assert_eq!(a.lu().solve(&b), Ok(x.clone()));
+    assert_ne!(a.lu_with(f64::first_non_zero).solve(&b), Ok(x));
+    assert_eq!(c.lu_with(f64::first_non_zero).u(), &d);
+}
+
+#[test]
+fn lu_test() {
+    let a = Matrix::from(vec![
+        vec![0.0, 0.0, 10.0],
+        vec![0.0, 3.0, 5.0],
+        vec![1.0, 2.0, 0.0],
+    ])
+    .unwrap();
+    let b = Matrix::from(vec![vec![1.0, 2.0, 3.0], vec![2.0, 4.0, 6.0]]).unwrap();
+    let c = Matrix::from(vec![vec![10.0], vec![8.0], vec![3.0]]).unwrap();
+    let x = Matrix::from(vec![vec![1.0], vec![1.0], vec![1.0]]).unwrap();
+
+    let lu = a.lu();
+    assert_eq!(lu.p() * a.clone(), lu.l().clone() * lu.u().clone());
+    assert_eq!(lu.det(), Ok(-30.0));
+    assert_eq!(lu.rank(), 3);
+    assert_eq!(lu.solve(&c), Ok(x));
+    assert_eq!(lu.inverse(), a.inverse());
+
+    let lu = b.lu();
+    assert_eq!(lu.rank(), 1);
+    assert_eq!(lu.pivots(), &vec![0]);
+    assert!(lu.det().is_err());
+    assert!(lu.solve(&c).is_err());
+}
+
+#[test]
+fn polynomial_test() {
+    use num::Rational64;
+
+    let a = Matrix::from(vec![
+        vec![0, 2, -1, 3],
+        vec![4, 1, 0, -2],
+        vec![3, 3, 5, 1],
+        vec![-1, 0, 2, 7],
+    ])
+    .unwrap();
+    let b: Matrix<Rational64> = Matrix::from(vec![
+        vec![2.into(), 1.into(), 0.into()],
+        vec![0.into(), 2.into(), 0.into()],
+        vec![0.into(), 0.into(), 2.into()],
+    ])
+    .unwrap();
+    let c = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    let p = a.characteristic_polynomial().unwrap();
+
+    assert_eq!(a.eval_polynomial(&p), Ok(Matrix::zero(4, 4)));
+    assert_eq!(
+        a.eval_polynomial(&[1, 0, 1]),
+        Ok(a.clone() * a + Matrix::identity(4))
+    );
+    assert_eq!(
+        b.minimal_polynomial(),
+        Ok(vec![4.into(), (-4).into(), 1.into()])
+    );
+    assert_eq!(
+        b.characteristic_polynomial(),
+        Ok(vec![(-8).into(), 12.into(), (-6).into(), 1.into()])
+    );
+    assert!(c.characteristic_polynomial().is_err());
+    assert!(c.eval_polynomial(&[1]).is_err());
+}
+
+#[test]
+fn eigen_test() {
+    use num::complex::Complex64;
+
+    let a = Matrix::from(vec![
+        vec![2.0, 0.0, 0.0],
+        vec![1.0, 3.0, 0.0],
+        vec![4.0, 5.0, 6.0],
+    ])
+    .unwrap();
+    // Companion matrix of (x - 1)(x² + 4) = x³ - x² + 4x - 4
+    let b = Matrix::from(vec![
+        vec![0.0, 0.0, 4.0],
+        vec![1.0, 0.0, -4.0],
+        vec![0.0, 1.0, 1.0],
+    ])
+    .unwrap();
+    let c = Matrix::from(vec![
+        vec![4.0, 1.0, -2.0, 2.0],
+        vec![1.0, 2.0, 0.0, 1.0],
+        vec![-2.0, 0.0, 3.0, -2.0],
+        vec![2.0, 1.0, -2.0, -1.0],
+    ])
+    .unwrap();
+    let d = Matrix::from(vec![vec![1.0, 2.0, 3.0]]).unwrap();
+
+    let close = |x: &Complex64, y: Complex64| (x - y).norm() < 1e-9;
+    let mut eigenvalues: Vec<f64> = a.eigenvalues().unwrap().iter().map(|x| x.re).collect();
+    eigenvalues.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    assert!(eigenvalues
+        .iter()
+        .zip([2.0, 3.0, 6.0])
+        .all(|(x, y)| (x - y).abs() < 1e-9));
+    let eigenvalues = b.eigenvalues().unwrap();
+    for expected in [
+        Complex64::new(1.0, 0.0),
+        Complex64::new(0.0, 2.0),
+        Complex64::new(0.0, -2.0),
+    ] {
+        assert!(eigenvalues.iter().any(|x| close(x, expected)));
+    }
+    let sum: Complex64 = c.eigenvalues().unwrap().iter().sum();
+    assert!(close(&sum, Complex64::new(8.0, 0.0)));
+
+    for m in [a, b, c] {
+        let n = m.height();
+        let (eigenvalues, eigenvectors) = m.eigenvectors().unwrap();
+        let complex: Matrix<Complex64> = m.matrix_into();
+        let columns = eigenvectors.columns();
+        for (lambda, v) in eigenvalues.iter().zip(columns) {
+            let v = Matrix::from(v.iter().map(|x| vec![*x]).collect()).unwrap();
+            let mut lv = v.clone();
+            lv.mul_scalar(*lambda);
+            let diff = complex.clone() * v - lv;
+            assert!((0..n).all(|i| diff.rows()[i][0].norm() < 1e-6));
+        }
+    }
+    assert!(d.eigenvalues().is_err());
+
+    // Repeated eigenvalues need independent eigenvectors
+    let identity: Matrix<f64> = Matrix::identity(3);
+    let e = Matrix::from(vec![
+        vec![2.0, 1.0, 1.0],
+        vec![1.0, 2.0, 1.0],
+        vec![1.0, 1.0, 2.0],
+    ])
+    .unwrap();
+    for m in [identity, e] {
+        let (eigenvalues, eigenvectors) = m.eigenvectors().unwrap();
+        let complex: Matrix<Complex64> = m.matrix_into();
+        let lv = Matrix::from_fn(3, 3, |i, j| eigenvectors[(i, j)] * eigenvalues[j]);
+        let diff = complex * eigenvectors.clone() - lv;
+        assert!(diff.as_slice().iter().all(|x| x.norm() < 1e-6));
+        assert_eq!(eigenvectors.rank(), 3);
+    }
+
+    // Defective matrices only get the eigenvectors they have
+    let f = Matrix::from(vec![
+        vec![3.0, 1.0, 0.0],
+        vec![0.0, 3.0, 0.0],
+        vec![0.0, 0.0, 5.0],
+    ])
+    .unwrap();
+    let (eigenvalues, eigenvectors) = f.eigenvectors().unwrap();
+    assert_eq!(eigenvalues.len(), 2);
+    assert_eq!(eigenvectors.width(), 2);
+    let complex: Matrix<Complex64> = f.matrix_into();
+    let lv = Matrix::from_fn(3, 2, |i, j| eigenvectors[(i, j)] * eigenvalues[j]);
+    let diff = complex * eigenvectors - lv;
+    assert!(diff.as_slice().iter().all(|x| x.norm() < 1e-6));
+}
+
+#[test]
+fn symmetric_eigen_test() {
+    let a = Matrix::from(vec![
+        vec![4.0, 1.0, -2.0, 2.0],
+        vec![1.0, 2.0, 0.0, 1.0],
+        vec![-2.0, 0.0, 3.0, -2.0],
+        vec![2.0, 1.0, -2.0, -1.0],
+    ])
+    .unwrap();
+    let b = Matrix::diagonal_matrix(vec![3.0, 1.0, 3.0]);
+    let c = Matrix::from(vec![vec![1.0, 2.0], vec![0.0, 1.0]]).unwrap();
+
+    for m in [a, b] {
+        let eigen = m.symmetric_eigen().unwrap();
+        let v = eigen.eigenvectors().clone();
+        let d = Matrix::diagonal_matrix(eigen.eigenvalues().clone());
+        assert!(eigen.eigenvalues().windows(2).all(|w| w[0] <= w[1]));
+        assert!(approx_eq(
+            &(v.transpose() * v.clone()),
+            &Matrix::identity(m.height())
+        ));
+        assert!(approx_eq(&(v.clone() * d * v.transpose()), &m));
+    }
+    assert_eq!(
+        c.symmetric_eigen(),
+        Err(crate::errors::MatrixError::NotSymmetric)
+    );
+}
+
+#[test]
+fn qr_test() {
+    let a = Matrix::from(vec![
+        vec![12.0, -51.0, 4.0],
+        vec![6.0, 167.0, -68.0],
+        vec![-4.0, 24.0, -41.0],
+    ])
+    .unwrap();
+    let b = Matrix::from(vec![
+        vec![1.0, 2.0],
+        vec![3.0, 4.0],
+        vec![5.0, 6.0],
+        vec![0.0, 1.0],
+    ])
+    .unwrap();
+    let c = Matrix::from(vec![vec![1.0, 2.0, 3.0, 4.0], vec![2.0, 4.0, 6.0, 8.0]]).unwrap();
+
+    for m in [a, b, c.clone()] {
+        for qr in [m.qr(), m.qr_givens(), m.qr_with_column_pivoting()] {
+            let q = qr.q().clone();
+            let r = qr.r();
+            assert!(approx_eq(
+                &(q.transpose() * q.clone()),
+                &Matrix::identity(m.height())
+            ));
+            assert!(approx_eq(&(q * r.clone()), &(m.clone() * qr.p())));
+            for (i, row) in r.rows().iter().enumerate() {
+                assert!(row.iter().take(i).all(|x| *x == 0.0));
+            }
+        }
+    }
+    let qr = c.qr_with_column_pivoting();
+    assert_eq!(qr.permutation()[0], 3);
+    assert_eq!(qr.rank(), 1);
+}
+
+#[test]
+fn svd_test() {
+    let a = Matrix::from(vec![
+        vec![1.0, 2.0, 3.0],
+        vec![4.0, 5.0, 6.0],
+        vec![7.0, 8.0, 10.0],
+        vec![-1.0, 0.0, 2.0],
+    ])
+    .unwrap();
+    let b = Matrix::from(vec![vec![1.0, 2.0, 3.0, 4.0], vec![2.0, 4.0, 6.0, 8.0]]).unwrap();
+    let c = Matrix::diagonal_matrix(vec![1.0, -4.0, 2.0]);
+
+    for m in [a.clone(), a.transpose(), b, c.clone()] {
+        let k = m.height().min(m.width());
+        let svd = m.svd().unwrap();
+        let u = svd.u().clone();
+        let vt = svd.vt().clone();
+        let s = Matrix::diagonal_matrix(svd.singular_values().clone());
+        assert_eq!((u.height(), u.width()), (m.height(), k));
+        assert_eq!((vt.height(), vt.width()), (k, m.width()));
+        assert!(svd.singular_values().windows(2).all(|w| w[0] >= w[1]));
+        assert!(svd.singular_values().iter().all(|x| *x >= 0.0));
+        assert!(approx_eq(
+            &(u.transpose() * u.clone()),
+            &Matrix::identity(k)
+        ));
+        assert!(approx_eq(
+            &(vt.clone() * vt.transpose()),
+            &Matrix::identity(k)
+        ));
+        assert!(approx_eq(&(u * s * vt), &m));
+        let values = m.singular_values().unwrap();
+        assert!(values
+            .iter()
+            .zip(svd.singular_values())
+            .all(|(x, y)| (x - y).abs() < 1e-9));
+    }
+    assert_eq!(c.singular_values(), Ok(vec![4.0, 2.0, 1.0]));
+}
+
+#[test]
+fn cholesky_ldlt_test() {
+    use crate::errors::MatrixError;
+    use num::Rational64;
+
+    let a = Matrix::from(vec![
+        vec![4.0, 12.0, -16.0],
+        vec![12.0, 37.0, -43.0],
+        vec![-16.0, -43.0, 98.0],
+    ])
+    .unwrap();
+    let l = Matrix::from(vec![
+        vec![2.0, 0.0, 0.0],
+        vec![6.0, 1.0, 0.0],
+        vec![-8.0, 5.0, 3.0],
+    ])
+    .unwrap();
+    let b = Matrix::from(vec![vec![1.0, 2.0], vec![2.0, 1.0]]).unwrap();
+    let c = Matrix::from(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+    let d: Matrix<Rational64> = Matrix::from(vec![
+        vec![2.into(), 1.into(), 0.into()],
+        vec![1.into(), 0.into(), 3.into()],
+        vec![0.into(), 3.into(), 1.into()],
+    ])
+    .unwrap();
+    let x = Matrix::from(vec![vec![1.0, 0.0], vec![-1.0, 2.0], vec![0.5, 1.0]]).unwrap();
+    let y: Matrix<Rational64> =
+        Matrix::from(vec![vec![1.into()], vec![(-1).into()], vec![2.into()]]).unwrap();
+
+    let cholesky = a.cholesky().unwrap();
+    assert_eq!(cholesky.l(), &l);
+    assert!(approx_eq(
+        &cholesky.solve(&(a.clone() * x.clone())).unwrap(),
+        &x
+    ));
+    assert_eq!(b.cholesky(), Err(MatrixError::NotPositiveDefinite));
+    assert_eq!(c.cholesky(), Err(MatrixError::NotSymmetric));
+
+    let ldlt = a.ldlt().unwrap();
+    assert!(approx_eq(
+        &(ldlt.l().clone() * ldlt.d_matrix() * ldlt.l().transpose()),
+        &(ldlt.p() * a.clone() * ldlt.p().transpose())
+    ));
+    assert!(approx_eq(&ldlt.solve(&(a * x.clone())).unwrap(), &x));
+    let ldlt = d.ldlt().unwrap();
+    assert_eq!(ldlt.d(), &vec![2.into(), Rational64::new(-1, 2), 19.into()]);
+    assert_eq!(ldlt.solve(&(d * y.clone())), Ok(y.clone()));
+    assert_eq!(c.ldlt(), Err(MatrixError::NotSymmetric));
+
+    // Symmetric indefinite matrices with zeros on the diagonal need 2×2 pivots
+    let e = Matrix::from(vec![vec![0.0, 1.0], vec![1.0, 0.0]]).unwrap();
+    let ldlt = e.ldlt().unwrap();
+    assert_eq!(ldlt.subdiagonal(), &vec![1.0]);
+    assert_eq!(ldlt.d_matrix(), e);
+    let z = Matrix::from(vec![vec![2.0], vec![-3.0]]).unwrap();
+    assert_eq!(ldlt.solve(&(e * z.clone())), Ok(z));
+    let f: Matrix<Rational64> = Matrix::from(vec![
+        vec![0.into(), 1.into(), 2.into()],
+        vec![1.into(), 0.into(), 3.into()],
+        vec![2.into(), 3.into(), 0.into()],
+    ])
+    .unwrap();
+    let ldlt = f.ldlt().unwrap();
+    assert_eq!(
+        ldlt.l().clone() * ldlt.d_matrix() * ldlt.l().transpose(),
+        ldlt.p() * f.clone() * ldlt.p().transpose()
+    );
+    assert_eq!(ldlt.solve(&(f * y.clone())), Ok(y));
+    let g = Matrix::from(vec![vec![1.0, 1.0], vec![1.0, 1.0]]).unwrap();
+    assert_eq!(g.ldlt(), Err(MatrixError::Singular));
+
+    // Computed matrices are only symmetric up to rounding errors
+    let h = Matrix::from(vec![
+        vec![0.1, 0.2, 0.3],
+        vec![0.4, 0.5, 0.6],
+        vec![0.7, 0.8, 1.0],
+    ])
+    .unwrap();
+    let k = h.transpose() * Matrix::diagonal_matrix(vec![0.3, -0.7, 1.1]) * h;
+    assert!(!k.is_symmetric());
+    let ldlt = k.ldlt().unwrap();
+    assert!(approx_eq(
+        &(ldlt.l().clone() * ldlt.d_matrix() * ldlt.l().transpose()),
+        &(ldlt.p() * k.clone() * ldlt.p().transpose())
+    ));
+    let mut k = k;
+    k[(0, 1)] += 1e-3;
+    assert_eq!(k.ldlt(), Err(MatrixError::NotSymmetric));
+}
+
+#[test]
+fn solve_test() {
+    use crate::errors::MatrixError;
+    use num::Rational64;
+
+    let a = Matrix::from(vec![
+        vec![2.0, 1.0, -1.0],
+        vec![-3.0, -1.0, 2.0],
+        vec![-2.0, 1.0, 2.0],
+    ])
+    .unwrap();
+    let b = Matrix::from(vec![vec![8.0, 1.0], vec![-11.0, 0.0], vec![-3.0, 2.0]]).unwrap();
+    let c = Matrix::from(vec![vec![1, 2, 1, 0], vec![2, 4, 0, 2], vec![3, 6, 1, 2]]).unwrap();
+    let c: Matrix<Rational64> = c.matrix_into();
+    let d: Matrix<Rational64> = Matrix::from(vec![vec![4], vec![6], vec![10]])
+        .unwrap()
+        .matrix_into();
+    let e: Matrix<Rational64> = Matrix::from(vec![vec![4], vec![6], vec![11]])
+        .unwrap()
+        .matrix_into();
+
+    let x = a.solve(&b).unwrap();
+    assert!(approx_eq(&(a.clone() * x), &b));
+    let x = c.solve(&d).unwrap();
+    assert_eq!(c.clone() * x, d);
+    assert_eq!(c.solve(&e), Err(MatrixError::Singular));
+
+    // Singular floating point systems are detected despite rounding errors.
+    let f = Matrix::from(vec![
+        vec![1.0, 2.0, 3.0],
+        vec![4.0, 5.0, 6.0],
+        vec![7.0, 8.0, 9.0],
+    ])
+    .unwrap();
+    let ones = Matrix::from(vec![vec![1.0]; 3]).unwrap();
+    let inconsistent = Matrix::from(vec![vec![1.0], vec![0.0], vec![0.0]]).unwrap();
+    assert!(f64::abs(f.det_in_field().unwrap()) < 1e-12);
+    assert_eq!(f.solve(&inconsistent), Err(MatrixError::Singular));
+    // The system is consistent, so a particular solution with the free variable set to zero is found.
+    let x = f.solve(&ones).unwrap();
+    let particular = Matrix::from(vec![vec![-1.0], vec![1.0], vec![0.0]]).unwrap();
+    assert!(approx_eq(&x, &particular));
+
+    // Small pivots are kept when the rank isn't asked for.
+    let g = Matrix::diagonal_matrix(vec![1e-17, 1.0]);
+    assert_eq!(g.det_in_field(), Ok(1e-17));
+    assert_eq!(g.lu().det(), Ok(1e-17));
+    assert_eq!(g.inverse(), Ok(Matrix::diagonal_matrix(vec![1e17, 1.0])));
+    let y = Matrix::from(vec![vec![1e-17], vec![2.0]]).unwrap();
+    assert_eq!(
+        g.lu().solve(&y),
+        Ok(Matrix::from(vec![vec![1.0], vec![2.0]]).unwrap())
+    );
+    assert_eq!(g.ldlt().unwrap().d(), &vec![1e-17, 1.0]);
+    let h = Matrix::from(vec![vec![f64::INFINITY, 1.0], vec![1.0, 1.0]]).unwrap();
+    assert_eq!(h.det_in_field(), Ok(f64::INFINITY));
+    assert_eq!(
+        a.solve(&b.transpose()),
+        Err(MatrixError::DimensionMismatch {
+            left: (3, 3),
+            right: (2, 3)
+        })
+    );
+}
+
+#[test]
+fn solution_set_test() {
+    use crate::SolutionSet;
+    use num::Rational64;
+
+    let a = Matrix::from(vec![
+        vec![1, 2, 0, 3, 1],
+        vec![2, 4, 1, 7, 0],
+        vec![-1, -2, 1, -2, -3],
+    ])
+    .unwrap();
+    let a: Matrix<Rational64> = a.matrix_into();
+    let b: Matrix<Rational64> = Matrix::from(vec![vec![1, 0], vec![3, 1], vec![0, 1]])
+        .unwrap()
+        .matrix_into();
+    let c: Matrix<Rational64> = Matrix::from(vec![vec![1], vec![3], vec![1]])
+        .unwrap()
+        .matrix_into();
+
+    let SolutionSet::Consistent {
+        particular,
+        nullspace,
+        pivot_columns,
+        free_columns,
+    } = a.solution_set(&b).unwrap()
+    else {
+        panic!("the system should be consistent");
+    };
+    assert_eq!(pivot_columns, vec![0, 2]);
+    assert_eq!(free_columns, vec![1, 3, 4]);
+    assert_eq!(a.clone() * particular, b);
+    assert_eq!(nullspace.width(), 3);
+    assert_eq!(a.clone() * nullspace, Matrix::zero(3, 3));
+    assert_eq!(a.solution_set(&c), Ok(SolutionSet::Inconsistent));
+    assert!(a.solution_set(&c.transpose()).is_err());
+
+    // Both solve and solution_set use the same tolerance for floating point systems.
+    let f = Matrix::from(vec![
+        vec![1.0, 2.0, 3.0],
+        vec![4.0, 5.0, 6.0],
+        vec![7.0, 8.0, 9.0],
+    ])
+    .unwrap();
+    let ones = Matrix::from(vec![vec![1.0]; 3]).unwrap();
+    let inconsistent = Matrix::from(vec![vec![1.0], vec![0.0], vec![0.0]]).unwrap();
+    let tiny = Matrix::from(vec![vec![1e-20]]).unwrap();
+    let one = Matrix::from(vec![vec![1.0]]).unwrap();
+    for (a, b) in [(&f, &ones), (&f, &inconsistent), (&tiny, &one)] {
+        let consistent = !matches!(a.solution_set(b), Ok(SolutionSet::Inconsistent));
+        assert_eq!(a.solve(b).is_ok(), consistent);
+    }
+    assert!(f.solve(&ones).is_ok());
+    assert!(tiny.solve(&one).is_err());
+}
+
+#[test]
+fn subspaces_test() {
+    use num::Rational64;
+
+    let a: Matrix<Rational64> =
+        Matrix::from(vec![vec![1, 2, 0, 3], vec![2, 4, 1, 7], vec![3, 6, 1, 10]])
+            .unwrap()
+            .matrix_into();
+    let b: Matrix<Rational64> = Matrix::from(vec![vec![1, 0], vec![2, 1], vec![3, 1]])
+        .unwrap()
+        .matrix_into();
+    let c: Matrix<Rational64> = Matrix::from(vec![vec![1, 2, 0, 3], vec![0, 0, 1, 1]])
+        .unwrap()
+        .matrix_into();
+    let d: Matrix<Rational64> =
+        Matrix::from(vec![vec![-2, -3], vec![1, 0], vec![0, -1], vec![0, 1]])
+            .unwrap()
+            .matrix_into();
+
+    assert_eq!(a.rank(), 2);
+    assert_eq!(a.pivot_columns(), vec![0, 2]);
+    assert_eq!(a.column_space(), b);
+    assert_eq!(a.row_space(), c);
+    assert_eq!(a.nullspace(), d);
+    assert_eq!(a * d, Matrix::zero(3, 2));
+
+    let e = Matrix::from(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+    assert_eq!(e.rank(), 2);
+    assert_eq!(e.nullspace().width(), 0);
+
+    // Rounding errors shouldn't make a singular matrix look invertible.
+    let f = Matrix::from(vec![
+        vec![1.0, 2.0, 3.0],
+        vec![4.0, 5.0, 6.0],
+        vec![7.0, 8.0, 9.0],
+    ])
+    .unwrap();
+    assert_eq!(f.rank(), 2);
+    assert_eq!(f.pivot_columns(), vec![0, 1]);
+    let nullspace = f.nullspace();
+    assert!(approx_eq(
+        &nullspace,
+        &Matrix::from(vec![vec![1.0], vec![-2.0], vec![1.0]]).unwrap()
+    ));
+    assert!(approx_eq(&(&f * &nullspace), &Matrix::zero(3, 1)));
+    assert_eq!(
+        f.reduced_echelon().matrix().row(2).to_matrix(),
+        Matrix::zero(1, 3)
+    );
+    assert_eq!(f.transpose().rank(), 2);
+    assert_eq!(f.column_space().width(), 2);
+    let g: Matrix<f64> = Matrix::hilbert(4);
+    assert_eq!(g.rank(), 4);
+    assert_eq!((g * 1e-30).rank(), 4);
+}
+
+#[test]
+fn least_squares_test() {
+    use crate::errors::MatrixError;
+
+    let a = Matrix::from(vec![
+        vec![1.0, 1.0, 1.0],
+        vec![1.0, 2.0, 4.0],
+        vec![1.0, 3.0, 9.0],
+        vec![1.0, 4.0, 16.0],
+    ])
+    .unwrap();
+    let x = Matrix::from(vec![vec![1.0, -2.0], vec![0.5, 0.0], vec![-1.0, 3.0]]).unwrap();
+    let b = a.clone() * x.clone();
+    assert!(approx_eq(&a.least_squares(&b).unwrap(), &x));
+    assert!(approx_eq(&a.least_squares_normal(&b).unwrap(), &x));
+
+    // The residual of an inconsistent system is orthogonal to the columns.
+    let c = Matrix::from(vec![vec![1.0], vec![0.0], vec![2.0], vec![-1.0]]).unwrap();
+    let y = a.least_squares(&c).unwrap();
+    let residual = a.clone() * y.clone() - c.clone();
+    assert!(approx_eq(&(a.transpose() * residual), &Matrix::zero(3, 1)));
+    assert!(approx_eq(&a.least_squares_normal(&c).unwrap(), &y));
+
+    let d = Matrix::from(vec![vec![1.0, 2.0], vec![2.0, 4.0], vec![3.0, 6.0]]).unwrap();
+    assert_eq!(
+        d.least_squares(&c),
+        Err(MatrixError::DimensionMismatch {
+            left: (3, 2),
+            right: (4, 1)
+        })
+    );
+    assert_eq!(
+        d.least_squares(&Matrix::zero(3, 1)),
+        Err(MatrixError::Singular)
+    );
+
+    // The pseudo-inverse satisfies the Moore–Penrose conditions.
+    for m in [a.clone(), a.transpose(), d.clone(), d.transpose()] {
+        let p = m.pseudo_inverse().unwrap();
+        assert!(approx_eq(&(m.clone() * p.clone() * m.clone()), &m));
+        assert!(approx_eq(&(p.clone() * m.clone() * p.clone()), &p));
+        assert!(approx_eq(
+            &(m.clone() * p.clone()).transpose(),
+            &(m.clone() * p.clone())
+        ));
+        assert!(approx_eq(&(p.clone() * m.clone()).transpose(), &(p * m)));
+    }
+    let e = Matrix::from(vec![vec![2.0, 1.0], vec![4.0, 4.0]]).unwrap();
+    assert!(approx_eq(
+        &e.pseudo_inverse().unwrap(),
+        &e.inverse().unwrap()
+    ));
+    assert!(approx_eq(
+        &a.pseudo_inverse().unwrap(),
+        &a.least_squares(&Matrix::identity(4)).unwrap()
+    ));
+}
+
+#[test]
+fn storage_test() {
+    let a = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    let b = Matrix::from(vec![vec![1, 4], vec![2, 5], vec![3, 6]]).unwrap();
+
+    assert_eq!(a.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(a.rows(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    assert_eq!(a.columns(), b.rows());
+    assert_eq!(a.transpose(), b);
+    assert_eq!(b.as_slice(), &[1, 4, 2, 5, 3, 6]);
+    assert_eq!(
+        a.clone() * b.clone(),
+        Matrix::from(vec![vec![14, 32], vec![32, 77]]).unwrap()
+    );
+    assert_eq!(
+        b * a.clone(),
+        Matrix::from(vec![vec![17, 22, 27], vec![22, 29, 36], vec![27, 36, 45]]).unwrap()
+    );
+    assert_eq!(a.submatrix(1, 1).as_slice(), &[1, 3]);
+    assert_eq!(format!("{a}"), "[[1, 2, 3], [4, 5, 6]]");
+}
+
+#[test]
+fn views_test() {
+    let mut a = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]).unwrap();
+    let b = Matrix::from(vec![vec![2, 3], vec![5, 6]]).unwrap();
+    let c = Matrix::from(vec![vec![2, 5], vec![3, 6]]).unwrap();
+
+    let view = a.view(0..2, 1..3);
+    assert_eq!(view.to_matrix(), b);
+    assert_eq!(view.transpose().to_matrix(), c);
+    assert_eq!(
+        view.transpose().view(1..2, 0..2).to_matrix().as_slice(),
+        &[3, 6]
+    );
+    assert_eq!(view.column(1).to_matrix().as_slice(), &[3, 6]);
+    assert_eq!(a.row(2).rows(), vec![vec![7, 8, 9]]);
+    assert_eq!(
+        a.column(0).iter().copied().collect::<Vec<_>>(),
+        vec![1, 4, 7]
+    );
+    assert_eq!(a.view(1..1, 0..3).height(), 0);
+    assert_eq!(view * view.transpose(), b.clone() * c.clone());
+    assert_eq!(view + view.transpose(), b.clone() + c.clone());
+    assert_eq!(view - view.transpose(), b.clone() - c.clone());
+    assert_eq!(-view, -b.clone());
+    assert_eq!(view * &c, b.clone() * c.clone());
+    assert_eq!(&b + view, b.clone() + b.clone());
+    assert_eq!(b.clone() - view, Matrix::zero(2, 2));
+    assert_eq!(view.transpose() + c.clone(), c.clone() + c.clone());
+
+    a.view_mut(1..3, 0..2)
+        .transpose()
+        .copy_from(c.as_view())
+        .unwrap();
+    let d = Matrix::from(vec![vec![1, 2, 3], vec![2, 3, 6], vec![5, 6, 9]]).unwrap();
+    assert_eq!(a, d);
+    assert!(a.view_mut(0..1, 0..3).copy_from(b.as_view()).is_err());
+    a.column_mut(2).fill(0);
+    a.row_mut(0).view_mut(0..1, 0..1).fill(-1);
+    let e = Matrix::from(vec![vec![-1, 2, 0], vec![2, 3, 0], vec![5, 6, 0]]).unwrap();
+    assert_eq!(a, e);
+
+    let f = Matrix::from(vec![
+        vec![4.0, 2.0, 9.0],
+        vec![2.0, 5.0, 9.0],
+        vec![1.0, 3.0, 9.0],
+    ])
+    .unwrap();
+    let block = f.view(0..2, 0..2);
+    assert_eq!(block.lu(), block.to_matrix().lu());
+    assert_eq!(block.qr(), block.to_matrix().qr());
+    assert_eq!(block.cholesky(), block.to_matrix().cholesky());
+    let copy = block.to_matrix();
+    assert_eq!(block.det(), copy.det());
+    assert_eq!(block.det_in_field(), copy.det_in_field());
+    assert_eq!(block.inverse(), copy.inverse());
+    assert_eq!(block.rank(), 2);
+    assert_eq!(f.view(0..3, 2..3).rank(), 1);
+    assert_eq!(block.reduced_echelon(), copy.reduced_echelon());
+    assert_eq!(block.ldlt(), copy.ldlt());
+    assert_eq!(block.eigenvalues(), copy.eigenvalues());
+    assert_eq!(block.eigenvectors(), copy.eigenvectors());
+    assert_eq!(block.symmetric_eigen(), copy.symmetric_eigen());
+    assert_eq!(block.pseudo_inverse(), copy.pseudo_inverse());
+    let rhs = Matrix::from(vec![vec![1.0], vec![2.0]]).unwrap();
+    assert_eq!(block.least_squares(&rhs), copy.least_squares(&rhs));
+}
+
+#[test]
+fn index_test() {
+    use crate::errors::MatrixError;
+
+    let mut a = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    let out_of_bounds = Err(MatrixError::IndexOutOfBounds {
+        index: (2, 0),
+        dimensions: (2, 3),
+    });
+
+    assert_eq!(a[(1, 0)], 4);
+    assert_eq!(a.get(0, 2), Some(&3));
+    assert_eq!(a.get(0, 3), None);
+    a[(0, 0)] = 7;
+    *a.get_mut(1, 2).unwrap() += 1;
+    assert_eq!(a.set(1, 1, 0), Ok(()));
+    assert_eq!(a.set(2, 0, 0), out_of_bounds);
+    assert_eq!(a.swap_entries((0, 1), (1, 0)), Ok(()));
+    assert_eq!(a.swap_entries((0, 1), (2, 0)), out_of_bounds);
+    assert_eq!(a, Matrix::from(vec![vec![7, 4, 3], vec![2, 0, 7]]).unwrap());
+
+    let view = a.view(0..2, 1..3).transpose();
+    assert_eq!(view[(1, 0)], 3);
+    assert_eq!(view.get(0, 1), Some(&0));
+    assert_eq!(view.get(2, 0), None);
+    let mut view = a.column_mut(2);
+    view[(1, 0)] = 1;
+    assert_eq!(view.get_mut(0, 1), None);
+    assert_eq!(a.as_slice(), &[7, 4, 3, 2, 0, 1]);
+}
+
+#[test]
+#[should_panic(expected = "index (0, 3) is out of bounds for a 2x3 matrix")]
+fn index_panic_test() {
+    let a = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    let _ = a[(0, 3)];
+}
+
+#[test]
+#[should_panic(expected = "index (5, 5) is out of bounds for a 2x2 matrix")]
+fn submatrix_panic_test() {
+    let a = Matrix::from(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    let _ = a.submatrix(5, 5);
+}
+
+#[test]
+#[should_panic(expected = "index (0, 0) is out of bounds for a 1x0 matrix")]
+fn submatrix_empty_panic_test() {
+    let a = Matrix::<i32>::zero(1, 0);
+    let _ = a.submatrix(0, 0);
+}
+
+#[test]
+fn checked_ops_test() {
+    use crate::errors::MatrixError;
+
+    let a = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    let b = Matrix::from(vec![vec![1, 0], vec![0, 1], vec![1, 1]]).unwrap();
+    let mismatch = |left, right| Err(MatrixError::DimensionMismatch { left, right });
+
+    assert_eq!(a.checked_add(&a), Ok(a.clone() + a.clone()));
+    assert_eq!(a.checked_sub(&a), Ok(Matrix::zero(2, 3)));
+    assert_eq!(a.checked_mul(&b), Ok(a.clone() * b.clone()));
+    assert_eq!(a.checked_add(&b), mismatch((2, 3), (3, 2)));
+    assert_eq!(a.checked_sub(&b), mismatch((2, 3), (3, 2)));
+    assert_eq!(a.checked_mul(&a), mismatch((2, 3), (2, 3)));
+    assert_eq!(a.as_view().checked_mul(&b.as_view()), a.checked_mul(&b));
+    assert_eq!(
+        a.as_view().checked_add(&b.as_view().transpose()),
+        a.checked_add(&b.transpose())
+    );
+    assert_eq!(b.row(0).checked_sub(&a.row(0)), mismatch((1, 2), (1, 3)));
+}
+
+#[test]
+#[should_panic(expected = "provided matrices have incompatible dimensions 2x3 and 2x3")]
+fn mul_panic_test() {
+    let a = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    let _ = a.clone() * a;
+}
+
+#[test]
+#[should_panic(expected = "provided matrices have incompatible dimensions 2x3 and 3x2")]
+fn add_assign_panic_test() {
+    let mut a = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    a += a.transpose();
+}
+
+#[test]
+fn reference_ops_test() {
+    let a = Matrix::from(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    let b = Matrix::from(vec![vec![0, 1], vec![1, 0]]).unwrap();
+    let sum = Matrix::from(vec![vec![1, 3], vec![4, 4]]).unwrap();
+    let product = Matrix::from(vec![vec![2, 1], vec![4, 3]]).unwrap();
+
+    assert_eq!(&a + &b, sum);
+    assert_eq!(&a + b.clone(), sum);
+    assert_eq!(a.clone() + &b, sum);
+    assert_eq!(&sum - &b, a);
+    assert_eq!(&a * &b, product);
+    assert_eq!(-&a, -a.clone());
+
+    let mut c = a.clone();
+    c += &b;
+    assert_eq!(c, sum);
+    c -= b.clone();
+    assert_eq!(c, a);
+    c *= &b;
+    assert_eq!(c, product);
+
+    let d = Matrix::from(vec![vec![2, 4], vec![6, 8]]).unwrap();
+    assert_eq!(&a * 2, d);
+    assert_eq!(&d / 2, a);
+    let mut e = a.clone();
+    e *= 2;
+    assert_eq!(e, d);
+    e /= 2;
+    assert_eq!(e, a);
+    assert_eq!(
+        Matrix::from(vec![vec![1.0, 3.0]]).unwrap() / 2.0,
+        Matrix::from(vec![vec![0.5, 1.5]]).unwrap()
+    );
+}
+
+#[test]
+fn empty_test() {
+    use crate::errors::MatrixError;
+
+    let shape = |m: &Matrix<f64>| (m.height(), m.width());
+    let a: Matrix<f64> = Matrix::from_shape(0, 0, vec![]).unwrap();
+    let b: Matrix<f64> = Matrix::from_shape(0, 3, vec![]).unwrap();
+    let c: Matrix<f64> = Matrix::from(vec![vec![], vec![]]).unwrap();
+
+    assert_eq!(Matrix::<i32>::from(vec![]), Err(MatrixError::Empty));
+    assert_eq!(
+        Matrix::from_shape(2, 1, vec![vec![1]]),
+        Err(MatrixError::DimensionMismatch {
+            left: (2, 1),
+            right: (1, 1)
+        })
+    );
+    assert_eq!(
+        Matrix::from_shape(1, 2, vec![vec![1]]),
+        Err(MatrixError::UnequalRows)
+    );
+    assert_eq!(a, Matrix::identity(0));
+    assert_eq!(shape(&b.transpose()), (3, 0));
+    assert_eq!(shape(&c), (2, 0));
+
+    assert_eq!(a.det(), Ok(1.0));
+    assert_eq!(a.det_in_field(), Ok(1.0));
+    assert_eq!(a.det_bareiss(), Ok(1.0));
+    assert_eq!(a.clone().trace(), Ok(0.0));
+    assert_eq!(a.inverse(), Ok(a.clone()));
+    assert_eq!(a.characteristic_polynomial(), Ok(vec![1.0]));
+    assert_eq!(c.clone() * b.clone(), Matrix::zero(2, 3));
+    assert_eq!(shape(&(b.clone() * b.transpose())), (0, 0));
+    assert_eq!(b.columns(), vec![Vec::<f64>::new(); 3]);
+
+    assert_eq!(b.rank(), 0);
+    assert_eq!(b.nullspace(), Matrix::identity(3));
+    assert_eq!(shape(&b.column_space()), (0, 0));
+    assert_eq!(shape(&c.nullspace()), (0, 0));
+    assert_eq!(c.solve(&Matrix::zero(2, 1)), Ok(Matrix::zero(0, 1)));
+    assert_eq!(b.solve(&Matrix::zero(0, 2)), Ok(Matrix::zero(3, 2)));
+
+    let qr = b.qr();
+    assert_eq!((shape(qr.q()), shape(qr.r())), ((0, 0), (0, 3)));
+    let svd = b.svd().unwrap();
+    assert_eq!((shape(svd.u()), shape(svd.vt())), ((0, 0), (0, 3)));
+    let svd = c.svd().unwrap();
+    assert_eq!((shape(svd.u()), shape(svd.vt())), ((2, 0), (0, 0)));
+    assert_eq!(c.pseudo_inverse(), Ok(Matrix::zero(0, 2)));
+    assert_eq!(a.eigenvalues(), Ok(vec![]));
+    assert_eq!(a.symmetric_eigen().unwrap().eigenvectors(), &a);
+}
+
+#[test]
+fn constructors_test() {
+    use crate::errors::MatrixError;
+
+    let a = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    let b: Matrix<i32> = [[1, 2, 3], [4, 5, 6]].into();
+    let c: Matrix<i32> = vec![[1, 2, 3], [4, 5, 6]].into_iter().collect();
+    let d: Matrix<i32> = std::iter::empty::<Vec<i32>>().collect();
+
+    assert_eq!(crate::matrix![1, 2, 3; 4, 5, 6], a);
+    assert_eq!(crate::matrix![1, 2, 3,; 4, 5, 6;], a);
+    assert_eq!(crate::matrix![1.5], Matrix::from(vec![vec![1.5]]).unwrap());
+    assert_eq!(b, a);
+    assert_eq!(c, a);
+    assert_eq!((d.height(), d.width()), (0, 0));
+    assert_eq!(Matrix::from_fn(2, 3, |i, j| (i * 3 + j + 1) as i32), a);
+    assert_eq!(
+        Matrix::from_flat(2, 3, vec![1, 2, 3, 4, 5, 6]),
+        Ok(a.clone())
+    );
+    assert_eq!(
+        Matrix::from_flat_column_major(2, 3, vec![1, 4, 2, 5, 3, 6]),
+        Ok(a)
+    );
+    assert_eq!(
+        Matrix::from_flat(2, 2, vec![1, 2, 3]),
+        Err(MatrixError::DimensionMismatch {
+            left: (2, 2),
+            right: (1, 3)
+        })
+    );
+    assert_eq!(
+        Matrix::from_flat_column_major(3, 1, vec![1, 2]),
+        Err(MatrixError::DimensionMismatch {
+            left: (3, 1),
+            right: (1, 2)
+        })
+    );
+    assert_eq!(Matrix::from_fn(0, 2, |_, _| 0).width(), 2);
+}
+
+#[test]
+#[should_panic(expected = "provided array has unequal rows")]
+fn collect_panic_test() {
+    let _: Matrix<i32> = vec![vec![1, 2], vec![3]].into_iter().collect();
+}
+
+#[test]
+fn special_matrices_test() {
+    use crate::errors::MatrixError;
+    use num::Rational64;
+
+    let xs = [1, 2, 3];
+    let coeffs = Matrix::from(vec![vec![1], vec![0], vec![2]]).unwrap();
+    let values = Matrix::from(vec![vec![3], vec![9], vec![19]]).unwrap();
+    assert_eq!(Matrix::vandermonde(&xs, 2) * coeffs, values);
+    assert_eq!(
+        Matrix::vandermonde(&xs, 0),
+        Matrix::from(vec![vec![1]; 3]).unwrap()
+    );
+
+    let h = Matrix::<Rational64>::hilbert(3);
+    assert_eq!(h[(2, 2)], Rational64::new(1, 5));
+    assert_eq!(h.det_in_field(), Ok(Rational64::new(1, 2160)));
+    assert!(h.is_symmetric());
+
+    let t = Matrix::toeplitz(&[1, 2, 3], &[1, 4, 5, 6]);
+    assert_eq!(
+        t,
+        Matrix::from(vec![vec![1, 4, 5, 6], vec![2, 1, 4, 5], vec![3, 2, 1, 4]]).unwrap()
+    );
+    let hankel = Matrix::hankel(&[1, 2, 3], &[3, 4, 5, 6]);
+    assert_eq!(
+        hankel,
+        Matrix::from(vec![vec![1, 2, 3, 4], vec![2, 3, 4, 5], vec![3, 4, 5, 6]]).unwrap()
+    );
+    let c = Matrix::circulant(&[1, 2, 3, 4]);
+    assert_eq!(c, Matrix::toeplitz(&[1, 2, 3, 4], &[1, 4, 3, 2]));
+
+    let poly = [-6, 11, -6, 1];
+    let companion = Matrix::companion(&poly).unwrap();
+    assert_eq!(companion.characteristic_polynomial(), Ok(poly.to_vec()));
+    let poly: Vec<Rational64> = [6, 4, 2, 0].iter().map(|&x| x.into()).collect();
+    let companion = Matrix::companion(&poly).unwrap();
+    let monic: Vec<Rational64> = [3, 2, 1].iter().map(|&x| x.into()).collect();
+    assert_eq!(companion.characteristic_polynomial(), Ok(monic));
+    assert_eq!(Matrix::<i32>::companion(&[0, 0]), Err(MatrixError::Empty));
+    assert_eq!(Matrix::companion(&[5]).map(|m| m.height()), Ok(0));
+
+    let p = Matrix::<i64>::pascal(5);
+    assert_eq!(p[(4, 4)], 70);
+    assert_eq!(p.det_bareiss(), Ok(1));
+    let j = Matrix::jordan_block(3, 3);
+    assert_eq!(j.minimal_polynomial(), j.characteristic_polynomial());
+    assert_eq!(j.characteristic_polynomial(), Ok(vec![-27, 27, -9, 1]));
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_test() {
+    use rand::{distributions::Uniform, rngs::StdRng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(0);
+    let m = Matrix::random(3, 4, &mut rng, Uniform::new(0.0, 1.0));
+    assert_eq!((m.height(), m.width()), (3, 4));
+    assert!(m.as_slice().iter().all(|x| (0.0..1.0).contains(x)));
+
+    let sparse = Matrix::random_sparse(20, 20, 0.1, &mut rng, Uniform::new_inclusive(1, 9));
+    let non_zero = sparse.as_slice().iter().filter(|&&x| x != 0).count();
+    assert!(non_zero > 0 && non_zero < 100);
+    let dense = Matrix::random_sparse(5, 5, 1.0, &mut rng, Uniform::new_inclusive(1, 9));
+    assert!(dense.as_slice().iter().all(|&x| x != 0));
+
+    for det in [-2, 1, 7] {
+        let m = Matrix::<i64>::random_with_det(5, det, &mut rng, Uniform::new_inclusive(-3, 3));
+        assert_eq!(m.unwrap().det_bareiss(), Ok(det));
+    }
+    let unimodular = Matrix::<i64>::random_with_det(4, 1, &mut rng, Uniform::new_inclusive(-3, 3));
+    let unimodular: Matrix<num::Rational64> = unimodular.unwrap().matrix_into();
+    let inverse = unimodular.inverse().unwrap();
+    assert!(inverse.as_slice().iter().all(|x| x.is_integer()));
+    let empty = Matrix::random_with_det(0, 1, &mut rng, Uniform::new(0, 1)).unwrap();
+    assert_eq!(empty, Matrix::zero(0, 0));
+    assert_eq!(empty.det(), Ok(1));
+    assert!(Matrix::random_with_det(0, 2, &mut rng, Uniform::new(0, 1)).is_err());
+
+    let q = Matrix::random_orthogonal(5, &mut rng);
+    assert!(approx_eq(&(q.transpose() * &q), &Matrix::identity(5)));
+
+    let spd = Matrix::random_spd(5, &mut rng);
+    assert!(spd.is_symmetric());
+    assert!(spd.cholesky().is_ok());
+}
+
+#[test]
+fn blocks_test() {
+    use crate::errors::MatrixError;
+
+    let a = Matrix::from(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    let b = Matrix::from(vec![vec![5], vec![6]]).unwrap();
+    let c = Matrix::from(vec![vec![7, 8]]).unwrap();
+    let d = Matrix::from(vec![vec![9]]).unwrap();
+    let m = Matrix::block(&[&[&a, &b], &[&c, &d]]).unwrap();
+    let n = Matrix::from(vec![vec![1, 2, 5], vec![3, 4, 6], vec![7, 8, 9]]).unwrap();
+    assert_eq!(m, n);
+    let top = Matrix::hstack(&[&a, &b]).unwrap();
+    let bottom = Matrix::hstack(&[&c, &d]).unwrap();
+    assert_eq!(Matrix::vstack(&[&top, &bottom]), Ok(n.clone()));
+
+    // Round trip through split_blocks.
+    let blocks = n.split_blocks(&[2, 1], &[2, 1]).unwrap();
+    assert_eq!(
+        blocks,
+        vec![vec![a.clone(), b.clone()], vec![c.clone(), d.clone()]]
+    );
+    let rows: Vec<Vec<&Matrix<i32>>> = blocks.iter().map(|row| row.iter().collect()).collect();
+    let rows: Vec<&[&Matrix<i32>]> = rows.iter().map(Vec::as_slice).collect();
+    assert_eq!(Matrix::block(&rows), Ok(n.clone()));
+    assert_eq!(
+        n.split_blocks(&[2, 2], &[3]),
+        Err(MatrixError::DimensionMismatch {
+            left: (3, 3),
+            right: (4, 3)
+        })
+    );
+
+    // Blocks that don't line up.
+    assert_eq!(Matrix::<i32>::hstack(&[]), Err(MatrixError::Empty));
+    assert_eq!(Matrix::<i32>::block(&[&[]]), Err(MatrixError::Empty));
+    assert_eq!(
+        Matrix::block(&[&[&a, &b], &[&c]]),
+        Err(MatrixError::UnequalRows)
+    );
+    assert_eq!(
+        Matrix::block(&[&[&a, &b], &[&d, &c]]),
+        Err(MatrixError::DimensionMismatch {
+            left: (1, 2),
+            right: (1, 1)
+        })
+    );
+    assert_eq!(
+        Matrix::vstack(&[&a, &b]),
+        Err(MatrixError::DimensionMismatch {
+            left: (2, 2),
+            right: (2, 1)
+        })
+    );
+
+    // Empty blocks are allowed as long as the dimensions agree.
+    let empty = Matrix::<i32>::zero(2, 0);
+    assert_eq!(Matrix::hstack(&[&empty, &a, &empty]), Ok(a.clone()));
+
+    let diag = Matrix::block_diag(&[&a, &c, &d]);
+    let n = Matrix::from(vec![
+        vec![1, 2, 0, 0, 0],
+        vec![3, 4, 0, 0, 0],
+        vec![0, 0, 7, 8, 0],
+        vec![0, 0, 0, 0, 9],
+    ])
+    .unwrap();
+    assert_eq!(diag, n);
+    assert_eq!(Matrix::<i32>::block_diag(&[]), Matrix::zero(0, 0));
+    assert_eq!(
+        Matrix::block_diag(&[&Matrix::identity(2), &Matrix::identity(1)]),
+        Matrix::<i32>::identity(3)
+    );
 }
@@ -0,0 +1,661 @@
+use crate::{
+    decompositions::{
+        Cholesky, Echelon, Ldlt, LuDecomposition, QrDecomposition, Svd, SymmetricEigen,
+    },
+    errors::MatrixError,
+    Matrix, Pivot, ToMatrix,
+};
+use num::{complex::Complex64, traits::One};
+use std::ops::{Add, Div, Index, IndexMut, Mul, Neg, Range, Sub};
+
+/// Borrowed rectangular block of a [`Matrix`], possibly transposed. Creating one doesn't copy
+/// any entries, see [`Matrix::view()`], [`Matrix::row()`] and [`Matrix::column()`].
+/// Arithmetic on views, or on a view and a matrix, gives a new [`Matrix`]. The common
+/// operations like determinants, inverses and decompositions are available on views too, and
+/// work on a copy of the entries. Anything else can be done with [`to_matrix`](Self::to_matrix()).
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixView<'a, T: ToMatrix> {
+    entries: &'a [T],
+    height: usize,
+    width: usize,
+    row_stride: usize,
+    column_stride: usize,
+}
+
+/// Mutably borrowed rectangular block of a [`Matrix`], possibly transposed. Writing to it
+/// changes the entries of the matrix, see [`Matrix::view_mut()`].
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, T: ToMatrix> {
+    entries: &'a mut [T],
+    height: usize,
+    width: usize,
+    row_stride: usize,
+    column_stride: usize,
+}
+
+/// Returns the bounds of the part of the entries covering the given block, and its shape.
+/// Panics if the block doesn't fit in a matrix of the given shape.
+fn block_bounds(
+    (height, width): (usize, usize),
+    (row_stride, column_stride): (usize, usize),
+    rows: &Range<usize>,
+    cols: &Range<usize>,
+) -> (Range<usize>, usize, usize) {
+    assert!(
+        rows.start <= rows.end && rows.end <= height,
+        "row range {rows:?} out of bounds for height {height}"
+    );
+    assert!(
+        cols.start <= cols.end && cols.end <= width,
+        "column range {cols:?} out of bounds for width {width}"
+    );
+    // Empty blocks don't borrow anything, their start might even be past the end.
+    let bounds = if rows.is_empty() || cols.is_empty() {
+        0..0
+    } else {
+        let start = rows.start * row_stride + cols.start * column_stride;
+        start..(rows.end - 1) * row_stride + (cols.end - 1) * column_stride + 1
+    };
+    (bounds, rows.len(), cols.len())
+}
+
+/// Returns the position of the entry at the given (0-indexed) row and column of a view with
+/// the given shape and strides, or an error if it's out of bounds.
+fn offset(
+    (height, width): (usize, usize),
+    (row_stride, column_stride): (usize, usize),
+    i: usize,
+    j: usize,
+) -> Result<usize, MatrixError> {
+    if i < height && j < width {
+        Ok(i * row_stride + j * column_stride)
+    } else {
+        Err(MatrixError::IndexOutOfBounds {
+            index: (i, j),
+            dimensions: (height, width),
+        })
+    }
+}
+
+impl<T: ToMatrix> Matrix<T> {
+    /// Returns a view of the whole matrix.
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            entries: &self.entries,
+            height: self.height,
+            width: self.width,
+            row_stride: self.width,
+            column_stride: 1,
+        }
+    }
+
+    /// Returns a mutable view of the whole matrix.
+    pub fn as_view_mut(&mut self) -> MatrixViewMut<'_, T> {
+        MatrixViewMut {
+            entries: &mut self.entries,
+            height: self.height,
+            width: self.width,
+            row_stride: self.width,
+            column_stride: 1,
+        }
+    }
+
+    /// Returns a view of the block of a matrix with the given (0-indexed) rows and columns.
+    /// It'll panic if the ranges are out of bounds.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]).unwrap();
+    /// let n = Matrix::from(vec![vec![5, 6], vec![8, 9]]).unwrap();
+    /// assert_eq!(m.view(1..3, 1..3).to_matrix(), n);
+    /// ```
+    pub fn view(&self, rows: Range<usize>, cols: Range<usize>) -> MatrixView<'_, T> {
+        self.as_view().view(rows, cols)
+    }
+
+    /// Returns a mutable view of the block of a matrix with the given (0-indexed) rows and
+    /// columns. It'll panic if the ranges are out of bounds.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let mut m = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// m.view_mut(0..2, 1..3).fill(0);
+    /// let n = Matrix::from(vec![vec![1, 0, 0], vec![4, 0, 0]]).unwrap();
+    /// assert_eq!(m, n);
+    /// ```
+    pub fn view_mut(&mut self, rows: Range<usize>, cols: Range<usize>) -> MatrixViewMut<'_, T> {
+        self.as_view_mut().into_view_mut(rows, cols)
+    }
+
+    /// Returns a view of the `i`-th (0-indexed) row of a matrix.
+    pub fn row(&self, i: usize) -> MatrixView<'_, T> {
+        self.view(i..i + 1, 0..self.width)
+    }
+
+    /// Returns a mutable view of the `i`-th (0-indexed) row of a matrix.
+    pub fn row_mut(&mut self, i: usize) -> MatrixViewMut<'_, T> {
+        let width = self.width;
+        self.view_mut(i..i + 1, 0..width)
+    }
+
+    /// Returns a view of the `j`-th (0-indexed) column of a matrix.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// assert_eq!(m.column(1).to_matrix(), Matrix::from(vec![vec![2], vec![4]]).unwrap());
+    /// ```
+    pub fn column(&self, j: usize) -> MatrixView<'_, T> {
+        self.view(0..self.height, j..j + 1)
+    }
+
+    /// Returns a mutable view of the `j`-th (0-indexed) column of a matrix.
+    pub fn column_mut(&mut self, j: usize) -> MatrixViewMut<'_, T> {
+        let height = self.height;
+        self.view_mut(0..height, j..j + 1)
+    }
+}
+
+impl<'a, T: ToMatrix> MatrixView<'a, T> {
+    /// Returns the height of a view.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the width of a view.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the entry at the given (0-indexed) position.
+    pub(crate) fn at(&self, i: usize, j: usize) -> T {
+        self.entries[i * self.row_stride + j * self.column_stride]
+    }
+
+    /// Returns the position of an entry in the borrowed storage, see [`offset`].
+    fn offset(&self, i: usize, j: usize) -> Result<usize, MatrixError> {
+        offset(
+            (self.height, self.width),
+            (self.row_stride, self.column_stride),
+            i,
+            j,
+        )
+    }
+
+    /// Returns a reference to the entry at the given (0-indexed) row and column,
+    /// or [`None`] if it's out of bounds, see [`Matrix::get()`].
+    pub fn get(&self, i: usize, j: usize) -> Option<&'a T> {
+        let entries = self.entries;
+        self.offset(i, j).ok().map(|k| &entries[k])
+    }
+
+    /// Returns a view of a block of a view, see [`Matrix::view()`].
+    pub fn view(&self, rows: Range<usize>, cols: Range<usize>) -> MatrixView<'a, T> {
+        let (bounds, height, width) = block_bounds(
+            (self.height, self.width),
+            (self.row_stride, self.column_stride),
+            &rows,
+            &cols,
+        );
+        MatrixView {
+            entries: &self.entries[bounds],
+            height,
+            width,
+            ..*self
+        }
+    }
+
+    /// Returns a view of the `i`-th (0-indexed) row of a view.
+    pub fn row(&self, i: usize) -> MatrixView<'a, T> {
+        self.view(i..i + 1, 0..self.width)
+    }
+
+    /// Returns a view of the `j`-th (0-indexed) column of a view.
+    pub fn column(&self, j: usize) -> MatrixView<'a, T> {
+        self.view(0..self.height, j..j + 1)
+    }
+
+    /// Returns the transpose of a view, without copying any entries.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let m = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// assert_eq!(m.as_view().transpose().to_matrix(), m.transpose());
+    /// ```
+    pub fn transpose(&self) -> MatrixView<'a, T> {
+        MatrixView {
+            height: self.width,
+            width: self.height,
+            row_stride: self.column_stride,
+            column_stride: self.row_stride,
+            ..*self
+        }
+    }
+
+    /// Returns an iterator over the entries of a view in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        let view = *self;
+        (0..view.height).flat_map(move |i| {
+            (0..view.width)
+                .map(move |j| &view.entries[i * view.row_stride + j * view.column_stride])
+        })
+    }
+
+    /// Returns the rows of a view as `Vec<Vec<T>>`.
+    pub fn rows(&self) -> Vec<Vec<T>> {
+        (0..self.height)
+            .map(|i| (0..self.width).map(|j| self.at(i, j)).collect())
+            .collect()
+    }
+
+    /// Copies the entries of a view into a new matrix.
+    pub fn to_matrix(&self) -> Matrix<T> {
+        Matrix {
+            entries: self.iter().copied().collect(),
+            height: self.height,
+            width: self.width,
+        }
+    }
+
+    /// Returns the sum of two views as a new matrix, see [`Matrix::checked_add()`].
+    pub fn checked_add(&self, other: &MatrixView<'_, T>) -> Result<Matrix<T>, MatrixError> {
+        self.zip_with(other, |x, y| x + y)
+    }
+
+    /// Returns the difference of two views as a new matrix, see [`Matrix::checked_sub()`].
+    pub fn checked_sub(&self, other: &MatrixView<'_, T>) -> Result<Matrix<T>, MatrixError> {
+        self.zip_with(other, |x, y| x - y)
+    }
+
+    /// Returns the product of two views as a new matrix, see [`Matrix::checked_mul()`].
+    pub fn checked_mul(&self, other: &MatrixView<'_, T>) -> Result<Matrix<T>, MatrixError> {
+        if self.width != other.height {
+            return Err(MatrixError::DimensionMismatch {
+                left: (self.height, self.width),
+                right: (other.height, other.width),
+            });
+        }
+        let mut out = Matrix::zero(self.height, other.width);
+        for (i, out_row) in out.row_slices_mut().enumerate() {
+            for k in 0..self.width {
+                let entry = self.at(i, k);
+                for (j, out_entry) in out_row.iter_mut().enumerate() {
+                    *out_entry = *out_entry + entry * other.at(k, j);
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    /// Combines the corresponding entries of two views of the same dimensions.
+    fn zip_with(
+        &self,
+        other: &MatrixView<'_, T>,
+        f: impl Fn(T, T) -> T,
+    ) -> Result<Matrix<T>, MatrixError> {
+        if self.height != other.height || self.width != other.width {
+            return Err(MatrixError::DimensionMismatch {
+                left: (self.height, self.width),
+                right: (other.height, other.width),
+            });
+        }
+        Ok(Matrix {
+            entries: self
+                .iter()
+                .zip(other.iter())
+                .map(|(x, y)| f(*x, *y))
+                .collect(),
+            height: self.height,
+            width: self.width,
+        })
+    }
+
+    /// Returns the LU decomposition of a view over a field, see [`Matrix::lu()`].
+    pub fn lu(&self) -> LuDecomposition<T>
+    where
+        T: One,
+        T: Pivot,
+        T: Div<Output = T>,
+    {
+        self.to_matrix().into_lu()
+    }
+
+    /// Solves `AX = B` for `X` over a field, where `A` is the view and `B` is given,
+    /// see [`Matrix::solve()`].
+    pub fn solve(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    where
+        T: One,
+        T: Pivot,
+        T: Div<Output = T>,
+    {
+        self.to_matrix().solve(rhs)
+    }
+
+    /// Returns the determinant of a square view, see [`Matrix::det()`].
+    pub fn det(&self) -> Result<T, MatrixError>
+    where
+        T: One,
+    {
+        self.to_matrix().det()
+    }
+
+    /// Returns the determinant of a square view over a field, see [`Matrix::det_in_field()`].
+    pub fn det_in_field(&self) -> Result<T, MatrixError>
+    where
+        T: One,
+        T: Pivot,
+        T: Div<Output = T>,
+    {
+        self.to_matrix().into_lu().det()
+    }
+
+    /// Returns the inverse of a square view over a field, see [`Matrix::inverse()`].
+    pub fn inverse(&self) -> Result<Matrix<T>, MatrixError>
+    where
+        T: One,
+        T: Pivot,
+        T: Div<Output = T>,
+    {
+        self.to_matrix().inverse()
+    }
+
+    /// Returns the rank of a view over a field, see [`Matrix::rank()`].
+    pub fn rank(&self) -> usize
+    where
+        T: One,
+        T: Pivot,
+        T: Div<Output = T>,
+    {
+        self.to_matrix().into_rank_revealing_lu().rank()
+    }
+
+    /// Returns the reduced row echelon form of a view over a field, see
+    /// [`Matrix::reduced_echelon()`].
+    pub fn reduced_echelon(&self) -> Echelon<T>
+    where
+        T: One,
+        T: Pivot,
+        T: Div<Output = T>,
+    {
+        self.to_matrix().reduced_echelon()
+    }
+
+    /// Returns the `LDLᵀ` decomposition of a symmetric view over a field, see [`Matrix::ldlt()`].
+    pub fn ldlt(&self) -> Result<Ldlt<T>, MatrixError>
+    where
+        T: One,
+        T: Pivot,
+        T: Div<Output = T>,
+    {
+        self.to_matrix().ldlt()
+    }
+}
+
+impl MatrixView<'_, f64> {
+    /// Returns the QR decomposition of a real view, see [`Matrix::qr()`].
+    pub fn qr(&self) -> QrDecomposition {
+        self.to_matrix().qr()
+    }
+
+    /// Returns the singular value decomposition of a real view, see [`Matrix::svd()`].
+    pub fn svd(&self) -> Result<Svd, MatrixError> {
+        self.to_matrix().svd()
+    }
+
+    /// Returns the Cholesky decomposition of a real view, see [`Matrix::cholesky()`].
+    pub fn cholesky(&self) -> Result<Cholesky, MatrixError> {
+        self.to_matrix().cholesky()
+    }
+
+    /// Returns the eigenvalues of a square real view, see [`Matrix::eigenvalues()`].
+    pub fn eigenvalues(&self) -> Result<Vec<Complex64>, MatrixError> {
+        self.to_matrix().eigenvalues()
+    }
+
+    /// Returns the eigenvalues and eigenvectors of a square real view,
+    /// see [`Matrix::eigenvectors()`].
+    pub fn eigenvectors(&self) -> Result<(Vec<Complex64>, Matrix<Complex64>), MatrixError> {
+        self.to_matrix().eigenvectors()
+    }
+
+    /// Returns the eigendecomposition of a real symmetric view,
+    /// see [`Matrix::symmetric_eigen()`].
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen, MatrixError> {
+        self.to_matrix().symmetric_eigen()
+    }
+
+    /// Returns the least squares solution of `AX = B`, where `A` is the view and `B` is given,
+    /// see [`Matrix::least_squares()`].
+    pub fn least_squares(&self, rhs: &Matrix<f64>) -> Result<Matrix<f64>, MatrixError> {
+        self.to_matrix().least_squares(rhs)
+    }
+
+    /// Returns the pseudo-inverse of a real view, see [`Matrix::pseudo_inverse()`].
+    pub fn pseudo_inverse(&self) -> Result<Matrix<f64>, MatrixError> {
+        self.to_matrix().pseudo_inverse()
+    }
+}
+
+impl<'a, T: ToMatrix> From<&'a Matrix<T>> for MatrixView<'a, T> {
+    fn from(matrix: &'a Matrix<T>) -> Self {
+        matrix.as_view()
+    }
+}
+
+impl<T: ToMatrix + PartialEq> PartialEq for MatrixView<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.height == other.height && self.width == other.width && self.iter().eq(other.iter())
+    }
+}
+
+impl<'a, T: ToMatrix> MatrixViewMut<'a, T> {
+    /// Returns the height of a view.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the width of a view.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the position of an entry in the borrowed storage, see [`offset`].
+    fn offset(&self, i: usize, j: usize) -> Result<usize, MatrixError> {
+        offset(
+            (self.height, self.width),
+            (self.row_stride, self.column_stride),
+            i,
+            j,
+        )
+    }
+
+    /// Returns a reference to the entry at the given (0-indexed) row and column,
+    /// or [`None`] if it's out of bounds, see [`Matrix::get()`].
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        self.offset(i, j).ok().map(|k| &self.entries[k])
+    }
+
+    /// Returns a mutable reference to the entry at the given (0-indexed) row and column,
+    /// or [`None`] if it's out of bounds, see [`Matrix::get_mut()`].
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        self.offset(i, j).ok().map(|k| &mut self.entries[k])
+    }
+
+    /// Returns an immutable view of the same block.
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            entries: &*self.entries,
+            height: self.height,
+            width: self.width,
+            row_stride: self.row_stride,
+            column_stride: self.column_stride,
+        }
+    }
+
+    /// Returns a mutable view of a block of a view, see [`Matrix::view_mut()`].
+    pub fn view_mut(&mut self, rows: Range<usize>, cols: Range<usize>) -> MatrixViewMut<'_, T> {
+        MatrixViewMut {
+            entries: &mut *self.entries,
+            ..*self
+        }
+        .into_view_mut(rows, cols)
+    }
+
+    /// Turns a view into a mutable view of a block of it.
+    fn into_view_mut(self, rows: Range<usize>, cols: Range<usize>) -> MatrixViewMut<'a, T> {
+        let (bounds, height, width) = block_bounds(
+            (self.height, self.width),
+            (self.row_stride, self.column_stride),
+            &rows,
+            &cols,
+        );
+        MatrixViewMut {
+            entries: &mut self.entries[bounds],
+            height,
+            width,
+            row_stride: self.row_stride,
+            column_stride: self.column_stride,
+        }
+    }
+
+    /// Returns the transpose of a view, without copying any entries.
+    pub fn transpose(self) -> MatrixViewMut<'a, T> {
+        MatrixViewMut {
+            height: self.width,
+            width: self.height,
+            row_stride: self.column_stride,
+            column_stride: self.row_stride,
+            entries: self.entries,
+        }
+    }
+
+    /// Applies a function to every entry of a view.
+    fn for_each(&mut self, mut f: impl FnMut(&mut T, usize, usize)) {
+        for i in 0..self.height {
+            for j in 0..self.width {
+                f(
+                    &mut self.entries[i * self.row_stride + j * self.column_stride],
+                    i,
+                    j,
+                );
+            }
+        }
+    }
+
+    /// Sets all entries of a view to the given value.
+    pub fn fill(&mut self, value: T) {
+        self.for_each(|entry, _, _| *entry = value);
+    }
+
+    /// Copies the entries of another view of the same shape into a view.
+    /// It'll throw an error if the shapes don't match.
+    /// # Example
+    /// ```
+    /// use matrix_basic::Matrix;
+    /// let mut m = Matrix::<i32>::zero(2, 3);
+    /// let n = Matrix::from(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// m.view_mut(0..2, 1..3).copy_from(n.as_view().transpose()).unwrap();
+    /// assert_eq!(m, Matrix::from(vec![vec![0, 1, 3], vec![0, 2, 4]]).unwrap());
+    /// ```
+    pub fn copy_from(&mut self, source: MatrixView<'_, T>) -> Result<(), MatrixError> {
+        if (self.height, self.width) != (source.height, source.width) {
+            return Err(MatrixError::DimensionMismatch {
+                left: (self.height, self.width),
+                right: (source.height, source.width),
+            });
+        }
+        self.for_each(|entry, i, j| *entry = source.at(i, j));
+        Ok(())
+    }
+
+    /// Copies the entries of a view into a new matrix.
+    pub fn to_matrix(&self) -> Matrix<T> {
+        self.as_view().to_matrix()
+    }
+}
+
+impl<T: ToMatrix> Index<(usize, usize)> for MatrixView<'_, T> {
+    type Output = T;
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+        match self.offset(i, j) {
+            Ok(k) => &self.entries[k],
+            Err(error) => panic!("{error}"),
+        }
+    }
+}
+
+impl<T: ToMatrix> Index<(usize, usize)> for MatrixViewMut<'_, T> {
+    type Output = T;
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+        match self.offset(i, j) {
+            Ok(k) => &self.entries[k],
+            Err(error) => panic!("{error}"),
+        }
+    }
+}
+
+impl<T: ToMatrix> IndexMut<(usize, usize)> for MatrixViewMut<'_, T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Self::Output {
+        match self.offset(i, j) {
+            Ok(k) => &mut self.entries[k],
+            Err(error) => panic!("{error}"),
+        }
+    }
+}
+
+/// Implements a binary operator for views, and for all combinations of views with owned and
+/// borrowed matrices, panicking with the error of the given checked method.
+macro_rules! impl_view_operator {
+    ($($trait:ident $method:ident $checked:ident;)*) => ($(
+        impl<T: ToMatrix> $trait<MatrixView<'_, T>> for MatrixView<'_, T> {
+            type Output = Matrix<T>;
+            fn $method(self, other: MatrixView<'_, T>) -> Self::Output {
+                match self.$checked(&other) {
+                    Ok(out) => out,
+                    Err(error) => panic!("{error}"),
+                }
+            }
+        }
+
+        impl<T: ToMatrix> $trait<&Matrix<T>> for MatrixView<'_, T> {
+            type Output = Matrix<T>;
+            fn $method(self, other: &Matrix<T>) -> Self::Output {
+                self.$method(other.as_view())
+            }
+        }
+
+        impl<T: ToMatrix> $trait<Matrix<T>> for MatrixView<'_, T> {
+            type Output = Matrix<T>;
+            fn $method(self, other: Matrix<T>) -> Self::Output {
+                self.$method(other.as_view())
+            }
+        }
+
+        impl<T: ToMatrix> $trait<MatrixView<'_, T>> for &Matrix<T> {
+            type Output = Matrix<T>;
+            fn $method(self, other: MatrixView<'_, T>) -> Self::Output {
+                self.as_view().$method(other)
+            }
+        }
+
+        impl<T: ToMatrix> $trait<MatrixView<'_, T>> for Matrix<T> {
+            type Output = Matrix<T>;
+            fn $method(self, other: MatrixView<'_, T>) -> Self::Output {
+                self.as_view().$method(other)
+            }
+        }
+    )*)
+}
+
+impl_view_operator! {
+    Add add checked_add;
+    Sub sub checked_sub;
+    Mul mul checked_mul;
+}
+
+impl<T: ToMatrix> Neg for MatrixView<'_, T> {
+    type Output = Matrix<T>;
+    fn neg(self) -> Self::Output {
+        -self.to_matrix()
+    }
+}